@@ -8,7 +8,7 @@
 //! This example connects to a WebSocket echo server, sends some messages
 //! and prints the echoed responses.
 
-use s9_websocket::{S9BlockingWebSocketClient, S9WebSocketClientHandler};
+use s9_websocket::{send_or_handler_error, S9WebSocketError, S9BlockingWebSocketClient, S9WebSocketClientHandler, CloseInfo, QuitReason};
 
 struct EchoHandler {
     message_count: usize,
@@ -24,7 +24,7 @@ impl S9WebSocketClientHandler<S9BlockingWebSocketClient> for EchoHandler {
         // Send a message after receiving one echo
         if self.message_count == 1 {
             println!("Sending Echo!");
-            client.send_text_message(&format!("Echoed: {}", text)).ok();
+            send_or_handler_error!(client, self, client.send_text_message(&format!("Echoed: {}", text)));
         }
 
         if self.message_count == 2 {
@@ -37,16 +37,20 @@ impl S9WebSocketClientHandler<S9BlockingWebSocketClient> for EchoHandler {
         println!("Received binary message: {} bytes", data.len());
     }
 
-    fn on_connection_closed(&mut self, _client: &mut S9BlockingWebSocketClient, reason: Option<String>) {
-        println!("Connection closed: {:?}", reason);
+    fn on_connection_closed(&mut self, _client: &mut S9BlockingWebSocketClient, info: CloseInfo) {
+        println!("Connection closed by {:?}: {:?}", info.initiated_by, info.reason);
     }
 
     fn on_error(&mut self, _client: &mut S9BlockingWebSocketClient, error: String) {
         eprintln!("Error: {}", error);
     }
 
-    fn on_quit(&mut self, _client: &mut S9BlockingWebSocketClient) {
-        println!("Client quit");
+    fn on_send_error(&mut self, _client: &mut S9BlockingWebSocketClient, error: S9WebSocketError) {
+        eprintln!("Send error: {}", error);
+    }
+
+    fn on_quit(&mut self, _client: &mut S9BlockingWebSocketClient, reason: QuitReason) {
+        println!("Client quit: {:?}", reason);
     }
 }
 
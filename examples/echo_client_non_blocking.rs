@@ -5,7 +5,7 @@
 //! from external threads (e.g., CTRL-C handler, timeout threads) using on_idle().
 
 use std::time::Duration;
-use s9_websocket::{S9NonBlockingWebSocketClient, NonBlockingOptions, S9WebSocketClientHandler};
+use s9_websocket::{send_or_handler_error, S9WebSocketError, S9NonBlockingWebSocketClient, NonBlockingOptions, S9WebSocketClientHandler, ConnectionInfo, CloseInfo, IdleInfo, QuitReason};
 use crossbeam_channel::{unbounded, Receiver};
 
 /// External signals that can be sent to the client from other threads
@@ -22,11 +22,11 @@ struct EchoHandler {
 
 impl S9WebSocketClientHandler<S9NonBlockingWebSocketClient> for EchoHandler {
     // Implement only what you need
-    fn on_activated(&mut self, _client: &mut S9NonBlockingWebSocketClient) {
-        println!("WebSocket client activated");
+    fn on_activated(&mut self, _client: &mut S9NonBlockingWebSocketClient, info: &ConnectionInfo) {
+        println!("WebSocket client activated for {} (HTTP {})", info.uri, info.status_code);
     }
 
-    fn on_idle(&mut self, client: &mut S9NonBlockingWebSocketClient) {
+    fn on_idle(&mut self, client: &mut S9NonBlockingWebSocketClient, _info: &IdleInfo) {
         // Check for external signals from other threads when no data is available (WouldBlock/TimedOut)
         if let Ok(signal) = self.signal_rx.try_recv() {
             match signal {
@@ -58,7 +58,7 @@ impl S9WebSocketClientHandler<S9NonBlockingWebSocketClient> for EchoHandler {
         if !self.closing && self.message_count <= 2 {
             // Send a message after receiving one echo
             println!("Sending Echo!");
-            client.send_text_message(&format!("Echoed: {}", text)).ok();
+            send_or_handler_error!(client, self, client.send_text_message(&format!("Echoed: {}", text)));
         }
     }
 
@@ -66,16 +66,20 @@ impl S9WebSocketClientHandler<S9NonBlockingWebSocketClient> for EchoHandler {
         println!("Received binary message: {} bytes", data.len());
     }
 
-    fn on_connection_closed(&mut self, _client: &mut S9NonBlockingWebSocketClient, reason: Option<String>) {
-        println!("Connection closed: {:?}", reason);
+    fn on_connection_closed(&mut self, _client: &mut S9NonBlockingWebSocketClient, info: CloseInfo) {
+        println!("Connection closed by {:?}: {:?}", info.initiated_by, info.reason);
     }
 
     fn on_error(&mut self, _client: &mut S9NonBlockingWebSocketClient, error: String) {
         eprintln!("Error: {}", error);
     }
 
-    fn on_quit(&mut self, _client: &mut S9NonBlockingWebSocketClient) {
-        println!("Client quit");
+    fn on_send_error(&mut self, _client: &mut S9NonBlockingWebSocketClient, error: S9WebSocketError) {
+        eprintln!("Send error: {}", error);
+    }
+
+    fn on_quit(&mut self, _client: &mut S9NonBlockingWebSocketClient, reason: QuitReason) {
+        println!("Client quit: {:?}", reason);
     }
 }
 
@@ -87,9 +91,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Configure a hot loop with no spin-waiting and zero delay for writing to the socket
     // Alternatively some spin-wait to reduce CPU usage while still being responsive
-    let options = NonBlockingOptions::new()
-        .spin_wait_duration(None)?
-        .nodelay(true);
+    let options = NonBlockingOptions::builder()
+        .spin_wait_duration(None)
+        .nodelay(true)
+        .build()?;
 
     // Connect to the WebSocket echo server
     println!("Connecting to echo.websocket.org...");
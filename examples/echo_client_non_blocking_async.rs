@@ -4,7 +4,7 @@
 //! This example connects to a WebSocket echo server, sends some messages
 //! and prints the echoed responses.
 
-use s9_websocket::{S9AsyncNonBlockingWebSocketClient, WebSocketEvent, NonBlockingOptions, ControlMessage};
+use s9_websocket::{S9AsyncClientBuilder, WebSocketEvent, ControlMessage};
 use std::time::Duration;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -13,31 +13,31 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_max_level(tracing::Level::INFO)
         .init();
 
-    // Configure with 10ms sleep between reads, to save CPU cycles
-    let options = NonBlockingOptions::new()
-        .spin_wait_duration(Some(Duration::from_millis(10)))?;
-
     // Connect to the WebSocket echo server
+    // configured with 10ms sleep between reads, to save CPU cycles
     println!("Connecting to echo.websocket.org...");
-    let mut client = S9AsyncNonBlockingWebSocketClient::connect("wss://echo.websocket.org", options)?;
+    let mut client = S9AsyncClientBuilder::new()
+        .spin_wait_duration(Some(Duration::from_millis(10)))
+        .connect("wss://echo.websocket.org")?;
 
-    // Start the event loop, which will start thread and return the handle immediately
-    let client_thread = client.run()?;
+    // Start the event loop, which will start thread and return immediately
+    client.run()?;
 
     // Send a text message using control channel
     client.control_tx.send(ControlMessage::SendText("Hello from s9_websocket!".to_string()))?;
     println!("Sent: Hello from s9_websocket!");
 
     let control_tx = client.control_tx.clone();
+    let event_rx = client.event_rx.clone();
 
     // Spawn a new thread for handling events
     let our_thread = std::thread::spawn(move || {
         // Handle events
         let mut message_count = 0;
         loop {
-            match client.event_rx.recv() {
-                Ok(WebSocketEvent::Activated) => {
-                    println!("WebSocket read thread activated");
+            match event_rx.recv() {
+                Ok(WebSocketEvent::Activated(info)) => {
+                    println!("WebSocket read thread activated for {}", info.uri);
                 }
                 Ok(WebSocketEvent::TextMessage(data)) => {
                     let text = String::from_utf8_lossy(&data);
@@ -47,24 +47,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     if message_count <= 2 {
                         // Echo the messages
                         println!("Sending Echo!");
-                        client.control_tx.send(ControlMessage::SendText(format!("Echoed: {}", text))).ok();
+                        control_tx.send(ControlMessage::SendText(format!("Echoed: {}", text))).ok();
                     }
 
                     // After closing, we can break our loop immediately
                     // or wait for a WebSocketEvent::ConnectionClosed + WebSocketEvent::Quit event
                     if message_count == 3 {
                         println!("Closing connection...");
-                        client.control_tx.send(ControlMessage::Close()).ok();
+                        control_tx.send(ControlMessage::Close()).ok();
                     }
                 }
-                Ok(WebSocketEvent::ConnectionClosed(reason)) => {
-                    println!("Connection closed: {:?}", reason);
+                Ok(WebSocketEvent::ConnectionClosed(info)) => {
+                    println!("Connection closed by {:?}: {:?}", info.initiated_by, info.reason);
                 }
                 Ok(WebSocketEvent::Error(err)) => {
                     eprintln!("Error: {}", err);
                 }
-                Ok(WebSocketEvent::Quit) => {
-                    println!("Client quit");
+                Ok(WebSocketEvent::Quit(reason)) => {
+                    println!("Client quit: {:?}", reason);
                     break;
                 }
                 _ => {}
@@ -73,11 +73,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // Outside our thread we can use message passing to send messages via control channel
-    control_tx.send(ControlMessage::SendText("Hello from s9_websocket again!".to_string()))?;
+    client.control_tx.send(ControlMessage::SendText("Hello from s9_websocket again!".to_string()))?;
 
     // Optionally wait for the event loops
     println!("Waiting for the event loops to finish...");
-    client_thread.join().ok();
+    client.wait().ok();
     our_thread.join().ok();
 
     println!("Example completed successfully");
@@ -34,9 +34,9 @@ use tungstenite::Error as TungsteniteError;
 ///
 /// # Error Categories
 ///
-/// - **Connection errors**: [`InvalidUri`](Self::InvalidUri), [`ConnectionClosed`](Self::ConnectionClosed)
+/// - **Connection errors**: [`InvalidUri`](Self::InvalidUri), [`HttpError`](Self::HttpError), [`Closed`](Self::Closed)
 /// - **Configuration errors**: [`InvalidConfiguration`](Self::InvalidConfiguration)
-/// - **Runtime errors**: [`SocketUnavailable`](Self::SocketUnavailable), [`Io`](Self::Io), [`Tungstenite`](Self::Tungstenite)
+/// - **Runtime errors**: [`SocketUnavailable`](Self::SocketUnavailable), [`SendQueueFull`](Self::SendQueueFull), [`Io`](Self::Io), [`Tungstenite`](Self::Tungstenite)
 ///
 /// # Examples
 ///
@@ -61,6 +61,20 @@ use tungstenite::Error as TungsteniteError;
 /// # Ok(())
 /// # }
 /// ```
+///
+/// # Boxing
+///
+/// `S9WebSocketError` is `Send + Sync + 'static`, so it converts into
+/// `Box<dyn std::error::Error + Send + Sync>` via the standard library's blanket `From` impl,
+/// e.g. with `?` or `.into()`:
+///
+/// ```
+/// use s9_websocket::S9WebSocketError;
+///
+/// let err = S9WebSocketError::SocketUnavailable;
+/// let boxed: Box<dyn std::error::Error + Send + Sync> = err.into();
+/// assert_eq!(boxed.to_string(), "Socket unavailable: run() was already called. The socket has been moved to the background thread.");
+/// ```
 #[derive(Debug)]
 pub enum S9WebSocketError {
     /// Invalid WebSocket URI was provided.
@@ -79,27 +93,46 @@ pub enum S9WebSocketError {
     /// ```
     InvalidUri(String),
 
+    /// The server rejected the WebSocket handshake with a non-101 HTTP response.
+    ///
+    /// Contains the HTTP status code and the response body, if any, decoded lossy as UTF-8.
+    /// Allows to distinguish e.g. authentication failures (401) from an overloaded server (503).
+    ///
+    /// # Example
+    /// ```
+    /// use s9_websocket::S9WebSocketError;
+    ///
+    /// let response = tungstenite::http::Response::builder()
+    ///     .status(401)
+    ///     .body(Some(b"Unauthorized".to_vec()))
+    ///     .unwrap();
+    /// let err = S9WebSocketError::from(tungstenite::Error::Http(response));
+    ///
+    /// assert!(matches!(&err, S9WebSocketError::HttpError { status: 401, body: Some(body) } if body == "Unauthorized"));
+    /// assert_eq!(err.to_string(), "HTTP error 401: Unauthorized");
+    /// ```
+    HttpError {
+        status: u16,
+        body: Option<String>,
+    },
+
     /// WebSocket connection was closed by the server or due to an error.
     ///
-    /// The optional `String` contains the close reason if provided by the server.
+    /// Contains the close code and reason of the close frame. The code defaults to `1000` (normal closure)
+    /// and the reason to an empty string if not provided.
     ///
     /// # Example
-    /// ```no_run
-    /// use s9_websocket::{S9AsyncNonBlockingWebSocketClient, WebSocketEvent, NonBlockingOptions};
-    ///
-    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let mut client = S9AsyncNonBlockingWebSocketClient::connect("wss://echo.websocket.org", NonBlockingOptions::new())?;
-    /// # let _handle = client.run()?;
-    /// match client.event_rx.recv() {
-    ///     Ok(WebSocketEvent::ConnectionClosed(reason)) => {
-    ///         println!("Connection closed: {:?}", reason);
-    ///     }
-    ///     _ => {}
-    /// }
-    /// # Ok(())
-    /// # }
     /// ```
-    ConnectionClosed(Option<String>),
+    /// use s9_websocket::S9WebSocketError;
+    ///
+    /// let err = S9WebSocketError::from(tungstenite::Error::ConnectionClosed);
+    /// assert!(matches!(&err, S9WebSocketError::Closed { code: 1000, reason } if reason.is_empty()));
+    /// assert_eq!(err.error_code(), Some(1000));
+    /// ```
+    Closed {
+        code: u16,
+        reason: String,
+    },
 
     /// Socket is unavailable because it was already moved to event loop thread.
     ///
@@ -107,6 +140,14 @@ pub enum S9WebSocketError {
     /// when attempting to call [`run()`](crate::S9AsyncNonBlockingWebSocketClient::run) multiple times.
     SocketUnavailable,
 
+    /// The bounded control channel is full and the message could not be queued.
+    ///
+    /// This error only occurs with the `try_*` send methods of [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient)
+    /// when the control channel has reached its capacity. It signals backpressure: the event loop thread
+    /// is still alive but has not yet drained pending control messages, so the send may be retried later.
+    /// A disconnected channel is reported as [`Closed`](Self::Closed) instead.
+    SendQueueFull,
+
     /// Invalid configuration was provided.
     ///
     /// This error occurs when configuration options contain invalid values, such as:
@@ -120,8 +161,9 @@ pub enum S9WebSocketError {
     ///
     /// # fn main() {
     /// // This will fail because spin_wait_duration cannot be zero
-    /// let result = NonBlockingOptions::new()
-    ///     .spin_wait_duration(Some(Duration::from_secs(0)));
+    /// let result = NonBlockingOptions::builder()
+    ///     .spin_wait_duration(Some(Duration::from_secs(0)))
+    ///     .build();
     /// assert!(result.is_err());
     /// # }
     /// ```
@@ -132,6 +174,21 @@ pub enum S9WebSocketError {
     /// This wraps standard [`std::io::Error`] and can occur during:
     /// - Network operations (connect, read, write)
     /// - Socket configuration (setting timeouts, TCP options)
+    ///
+    /// Displayed as `IO error (os error <code>): <message>` if the error carries an OS error code.
+    ///
+    /// # Example
+    /// ```
+    /// use s9_websocket::S9WebSocketError;
+    ///
+    /// let err = S9WebSocketError::Io(std::io::Error::from_raw_os_error(111));
+    /// let message = err.to_string();
+    /// assert!(message.starts_with("IO error (os error 111): "));
+    /// assert_eq!(message.matches("os error").count(), 1);
+    ///
+    /// let err = S9WebSocketError::Io(std::io::Error::other("broken pipe"));
+    /// assert_eq!(err.to_string(), "IO error: broken pipe");
+    /// ```
     Io(std::io::Error),
 
     /// An error from the underlying tungstenite WebSocket library.
@@ -143,22 +200,160 @@ pub enum S9WebSocketError {
     Tungstenite(TungsteniteError),
 }
 
+/// Maximum number of source errors appended by [`S9WebSocketError::display_chain`]
+const MAX_SOURCE_DEPTH: usize = 3;
+
 impl fmt::Display for S9WebSocketError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.display_chain())
+    }
+}
+
+impl S9WebSocketError {
+    /// Writes the message of this error without its sources
+    fn write_message(&self, f: &mut impl fmt::Write) -> fmt::Result {
         match self {
             S9WebSocketError::InvalidUri(uri) => write!(f, "Invalid URI: {}", uri),
-            S9WebSocketError::ConnectionClosed(reason) => {
-                match reason {
-                    Some(r) => write!(f, "Connection closed: {}", r),
-                    None => write!(f, "Connection closed without reason"),
+            S9WebSocketError::HttpError { status, body } => match body {
+                Some(body) if !body.is_empty() => write!(f, "HTTP error {}: {}", status, body),
+                _ => write!(f, "HTTP error {}", status),
+            },
+            S9WebSocketError::Closed { code, reason } => {
+                if reason.is_empty() {
+                    write!(f, "Connection closed with code {}", code)
+                } else {
+                    write!(f, "Connection closed with code {}: {}", code, reason)
                 }
             }
-            S9WebSocketError::SocketUnavailable => write!(f, "Socket already moved to thread"),
+            S9WebSocketError::SocketUnavailable => write!(f, "Socket unavailable: run() was already called. The socket has been moved to the background thread."),
+            S9WebSocketError::SendQueueFull => write!(f, "Control channel is full"),
             S9WebSocketError::InvalidConfiguration(msg) => write!(f, "Invalid configuration: {}", msg),
-            S9WebSocketError::Io(err) => write!(f, "IO error: {}", err),
+            S9WebSocketError::Io(err) => match os_error_message(err) {
+                Some(message) => f.write_str(&message),
+                None => write!(f, "IO error: {}", err),
+            },
             S9WebSocketError::Tungstenite(err) => write!(f, "WebSocket error: {}", err),
         }
     }
+
+    /// Returns the error message followed by its source chain, as used by [`Display`](fmt::Display).
+    ///
+    /// Appends `" caused by: <source>"` for up to 3 levels of [`source()`](std::error::Error::source).
+    /// Sources already contained in the message, e.g. the wrapped error of [`Io`](Self::Io), are skipped.
+    /// I/O errors carrying an OS error code are shown as `IO error (os error <code>): <message>`.
+    ///
+    /// ```
+    /// use std::fmt;
+    /// use s9_websocket::S9WebSocketError;
+    ///
+    /// #[derive(Debug)]
+    /// struct Handshake(std::io::Error);
+    ///
+    /// impl fmt::Display for Handshake {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "handshake failed")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for Handshake {
+    ///     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let refused = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "connection refused");
+    /// let io_err = std::io::Error::other(Handshake(refused));
+    /// let err = S9WebSocketError::Tungstenite(tungstenite::Error::Io(io_err));
+    ///
+    /// assert_eq!(err.display_chain(), "WebSocket error: IO error: handshake failed caused by: connection refused");
+    /// assert_eq!(err.to_string(), err.display_chain());
+    /// ```
+    pub fn display_chain(&self) -> String {
+        let mut chain = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_message(&mut chain);
+
+        let mut source = std::error::Error::source(self);
+        for _ in 0..MAX_SOURCE_DEPTH {
+            let Some(err) = source else { break };
+            let mut message = err.to_string();
+            if !chain.contains(&message) {
+                if let Some(os_message) = err.downcast_ref::<std::io::Error>().and_then(os_error_message) {
+                    message = os_message;
+                }
+            }
+            if !chain.contains(&message) {
+                chain.push_str(" caused by: ");
+                chain.push_str(&message);
+            }
+            source = err.source();
+        }
+        chain
+    }
+}
+
+/// Formats an I/O error carrying an OS error code as `IO error (os error <code>): <message>`.
+///
+/// The ` (os error <code>)` suffix of the standard library's `Display` is moved to the front,
+/// so the code is shown once at a fixed position.
+fn os_error_message(err: &std::io::Error) -> Option<String> {
+    let code = err.raw_os_error()?;
+    let message = err.to_string();
+    let suffix = format!(" (os error {})", code);
+    let message = message.strip_suffix(&suffix).unwrap_or(&message);
+    Some(format!("IO error (os error {}): {}", code, message))
+}
+
+/// Clones the error.
+///
+/// [`Io`](S9WebSocketError::Io) and [`Tungstenite`](S9WebSocketError::Tungstenite) wrap errors which are not
+/// clonable, these are cloned lossy as [`InvalidConfiguration`](S9WebSocketError::InvalidConfiguration) carrying
+/// the message of the wrapped error. Use [`try_clone()`](S9WebSocketError::try_clone) to avoid the lossy conversion.
+///
+/// ```
+/// use s9_websocket::S9WebSocketError;
+///
+/// let err = S9WebSocketError::Io(std::io::Error::other("broken pipe"));
+/// assert!(matches!(err.clone(), S9WebSocketError::InvalidConfiguration(msg) if msg == "broken pipe"));
+/// assert!(err.try_clone().is_none());
+///
+/// let err = S9WebSocketError::Closed { code: 1001, reason: "bye".to_string() };
+/// assert!(matches!(err.try_clone(), Some(S9WebSocketError::Closed { code: 1001, reason }) if reason == "bye"));
+/// ```
+impl Clone for S9WebSocketError {
+    fn clone(&self) -> Self {
+        match self {
+            S9WebSocketError::InvalidUri(uri) => S9WebSocketError::InvalidUri(uri.clone()),
+            S9WebSocketError::HttpError { status, body } => S9WebSocketError::HttpError { status: *status, body: body.clone() },
+            S9WebSocketError::Closed { code, reason } => S9WebSocketError::Closed { code: *code, reason: reason.clone() },
+            S9WebSocketError::SocketUnavailable => S9WebSocketError::SocketUnavailable,
+            S9WebSocketError::SendQueueFull => S9WebSocketError::SendQueueFull,
+            S9WebSocketError::InvalidConfiguration(msg) => S9WebSocketError::InvalidConfiguration(msg.clone()),
+            S9WebSocketError::Io(err) => S9WebSocketError::InvalidConfiguration(format!("{}", err)),
+            S9WebSocketError::Tungstenite(err) => S9WebSocketError::InvalidConfiguration(format!("{}", err)),
+        }
+    }
+}
+
+impl S9WebSocketError {
+    /// Clones the error without lossy conversion.
+    ///
+    /// Returns `None` for [`Io`](Self::Io) and [`Tungstenite`](Self::Tungstenite), as the wrapped errors are not clonable.
+    pub fn try_clone(&self) -> Option<Self> {
+        match self {
+            S9WebSocketError::Io(_) | S9WebSocketError::Tungstenite(_) => None,
+            _ => Some(self.clone()),
+        }
+    }
+
+    /// Returns the close code for [`Closed`](Self::Closed), `None` for all other errors.
+    #[inline]
+    pub fn error_code(&self) -> Option<u16> {
+        match self {
+            S9WebSocketError::Closed { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
 }
 
 impl std::error::Error for S9WebSocketError {
@@ -177,7 +372,7 @@ impl From<TungsteniteError> for S9WebSocketError {
     fn from(err: TungsteniteError) -> Self {
         match err {
             TungsteniteError::ConnectionClosed => {
-                S9WebSocketError::ConnectionClosed(Some(err.to_string()))
+                S9WebSocketError::Closed { code: 1000, reason: String::new() }
             }
             TungsteniteError::Io(io_err) => {
                 S9WebSocketError::Io(io_err)
@@ -185,6 +380,11 @@ impl From<TungsteniteError> for S9WebSocketError {
             TungsteniteError::Url(url_err) => {
                 S9WebSocketError::InvalidUri(url_err.to_string())
             }
+            TungsteniteError::Http(response) => {
+                let status = response.status().as_u16();
+                let body = response.into_body().map(|body| String::from_utf8_lossy(&body).into_owned());
+                S9WebSocketError::HttpError { status, body }
+            }
             _ => S9WebSocketError::Tungstenite(err),
         }
     }
@@ -197,9 +397,44 @@ impl From<std::io::Error> for S9WebSocketError {
     }
 }
 
+/// Wraps an [`anyhow::Error`] as [`InvalidConfiguration`](S9WebSocketError::InvalidConfiguration) carrying
+/// its debug representation, including the context chain. Requires the `anyhow` feature.
+///
+/// The opposite direction needs no feature, `S9WebSocketError` converts into `anyhow::Error` via anyhow's
+/// blanket `From` impl, and [`anyhow::Context`] adds `context()` to [`S9Result`]:
+///
+/// ```
+/// use anyhow::Context;
+/// use s9_websocket::{S9Result, S9WebSocketError};
+///
+/// fn connect() -> S9Result<()> {
+///     Err(S9WebSocketError::SocketUnavailable)
+/// }
+///
+/// let err: anyhow::Error = connect().context("connecting to the feed").unwrap_err();
+/// assert_eq!(err.to_string(), "connecting to the feed");
+///
+/// let err = S9WebSocketError::from(err);
+/// assert!(matches!(err, S9WebSocketError::InvalidConfiguration(msg) if msg.starts_with("connecting to the feed")));
+/// ```
+#[cfg(feature = "anyhow")]
+impl From<anyhow::Error> for S9WebSocketError {
+    fn from(err: anyhow::Error) -> Self {
+        S9WebSocketError::InvalidConfiguration(format!("{:?}", err))
+    }
+}
+
+// anyhow::Error and anyhow::Context require errors to be Send + Sync + 'static
+#[cfg(feature = "anyhow")]
+const _: fn() = {
+    fn assert_anyhow_compatible<E: std::error::Error + Send + Sync + 'static>() {}
+    assert_anyhow_compatible::<S9WebSocketError>
+};
+
 /// Convenience type alias for `Result<T, S9WebSocketError>`.
 ///
 /// This type is used throughout the S9 WebSocket API for operations that can fail.
+/// Import [`S9WebSocketError`] together with it to match on the error variants.
 ///
 /// # Examples
 ///
@@ -210,4 +445,105 @@ impl From<std::io::Error> for S9WebSocketError {
 ///     S9NonBlockingWebSocketClient::connect("wss://echo.websocket.org", NonBlockingOptions::new())
 /// }
 /// ```
-pub type S9Result<T> = Result<T, S9WebSocketError>;
\ No newline at end of file
+///
+/// Matching all variants:
+///
+/// ```
+/// use s9_websocket::{S9Result, S9WebSocketError};
+///
+/// fn describe(result: S9Result<()>) -> String {
+///     match result {
+///         Ok(()) => "ok".to_string(),
+///         Err(S9WebSocketError::InvalidUri(uri)) => format!("fix the URI {}", uri),
+///         Err(S9WebSocketError::HttpError { status: 401, .. }) => "check the credentials".to_string(),
+///         Err(S9WebSocketError::HttpError { status, .. }) => format!("handshake rejected with {}", status),
+///         Err(S9WebSocketError::Closed { code, reason }) => format!("closed with {}: {}", code, reason),
+///         Err(S9WebSocketError::SocketUnavailable) => "run() was already called".to_string(),
+///         Err(S9WebSocketError::SendQueueFull) => "retry later".to_string(),
+///         Err(S9WebSocketError::InvalidConfiguration(msg)) => format!("fix the options: {}", msg),
+///         Err(S9WebSocketError::Io(err)) => format!("network error: {}", err),
+///         Err(S9WebSocketError::Tungstenite(err)) => format!("protocol error: {}", err),
+///     }
+/// }
+///
+/// assert_eq!(describe(Err(S9WebSocketError::SendQueueFull)), "retry later");
+/// ```
+pub type S9Result<T> = Result<T, S9WebSocketError>;
+
+/// Idiomatic alias of [`S9Result`], available as `s9_websocket::Result<T>`.
+///
+/// The error type defaults to [`S9WebSocketError`]. It can be overridden, so a glob import
+/// `use s9_websocket::*` shadowing the prelude's `Result` keeps two-parameter usage working.
+///
+/// ```
+/// use s9_websocket::*;
+///
+/// fn check(options: &NonBlockingOptions) -> Result<()> {
+///     options.validate()
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     check(&NonBlockingOptions::new())?;
+///     Ok(())
+/// }
+/// ```
+pub type Result<T, E = S9WebSocketError> = std::result::Result<T, E>;
+
+/// Extension methods for [`S9Result`] to discard errors of fire-and-forget operations with logging.
+///
+/// Unlike `.ok()`, the error is not silently dropped but logged via `tracing`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use s9_websocket::{S9NonBlockingWebSocketClient, S9ResultExt, S9WebSocketClientHandler};
+///
+/// struct AckHandler;
+///
+/// impl S9WebSocketClientHandler<S9NonBlockingWebSocketClient> for AckHandler {
+///     fn on_text_message(&mut self, client: &mut S9NonBlockingWebSocketClient, _data: &[u8]) {
+///         client.send_text_message("ack").log_and_ignore("sending ack");
+///     }
+/// }
+/// ```
+///
+/// ```
+/// use s9_websocket::{S9Result, S9ResultExt, S9WebSocketError};
+///
+/// let result: S9Result<u32> = Err(S9WebSocketError::SocketUnavailable);
+/// assert_eq!(result.log_and_discard(), None);
+///
+/// let result: S9Result<u32> = Ok(42);
+/// assert_eq!(result.log_and_discard(), Some(42));
+/// ```
+pub trait S9ResultExt<T> {
+    /// Logs the error at WARN level with the given context and discards the result.
+    fn log_and_ignore(self, context: &str);
+
+    /// Logs the error at DEBUG level and returns the success value, if any.
+    fn log_and_discard(self) -> Option<T>;
+}
+
+impl<T> S9ResultExt<T> for S9Result<T> {
+    #[inline]
+    fn log_and_ignore(self, context: &str) {
+        if let Err(err) = self {
+            if tracing::enabled!(tracing::Level::WARN) {
+                tracing::warn!("Ignoring error in {}: {}", context, err);
+            }
+        }
+    }
+
+    #[inline]
+    fn log_and_discard(self) -> Option<T> {
+        match self {
+            Ok(value) => Some(value),
+            Err(err) => {
+                if tracing::enabled!(tracing::Level::DEBUG) {
+                    tracing::debug!("Discarding error: {}", err);
+                }
+                None
+            }
+        }
+    }
+}
@@ -48,8 +48,8 @@
 //! **Key characteristics:**
 //! - Spawns dedicated background thread
 //! - Non-blocking socket I/O
-//! - Event delivery via [`Receiver<WebSocketEvent>`](crossbeam_channel::Receiver)
-//! - Control via [`Sender<ControlMessage>`](crossbeam_channel::Sender)
+//! - Event delivery via [`EventReceiver`], or `std::sync::mpsc` with [`StdChannelBackend`]
+//! - Control via [`ControlSender`]
 //! - Thread-safe
 //!
 //! ## Quick Start
@@ -57,7 +57,7 @@
 //! ### Non-blocking Client (with handler callbacks)
 //!
 //! ```no_run
-//! use s9_websocket::{S9NonBlockingWebSocketClient, S9WebSocketClientHandler, NonBlockingOptions};
+//! use s9_websocket::{S9NonBlockingWebSocketClient, S9WebSocketClientHandler, NonBlockingOptions, CloseInfo};
 //! use std::time::Duration;
 //!
 //! struct MyHandler;
@@ -69,8 +69,8 @@
 //!         client.close();
 //!     }
 //!
-//!     fn on_connection_closed(&mut self, _client: &mut S9NonBlockingWebSocketClient, reason: Option<String>) {
-//!         println!("Connection closed: {:?}", reason);
+//!     fn on_connection_closed(&mut self, _client: &mut S9NonBlockingWebSocketClient, info: CloseInfo) {
+//!         println!("Connection closed by {:?}: {:?}", info.initiated_by, info.reason);
 //!     }
 //!
 //!     fn on_error(&mut self, _client: &mut S9NonBlockingWebSocketClient, error: String) {
@@ -79,8 +79,9 @@
 //! }
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
-//! let options = NonBlockingOptions::new()
-//!     .spin_wait_duration(Some(Duration::from_millis(10)))?;
+//! let options = NonBlockingOptions::builder()
+//!     .spin_wait_duration(Some(Duration::from_millis(10)))
+//!     .build()?;
 //!
 //! let mut client = S9NonBlockingWebSocketClient::connect("wss://echo.websocket.org", options)?;
 //! client.send_text_message("Hello!")?;
@@ -94,7 +95,7 @@
 //! ### Blocking Client
 //!
 //! ```no_run
-//! use s9_websocket::{S9BlockingWebSocketClient, S9WebSocketClientHandler, BlockingOptions};
+//! use s9_websocket::{S9BlockingWebSocketClient, S9WebSocketClientHandler, BlockingOptions, CloseInfo};
 //!
 //! struct MyHandler;
 //!
@@ -105,8 +106,8 @@
 //!         client.close();
 //!     }
 //!
-//!     fn on_connection_closed(&mut self, _client: &mut S9BlockingWebSocketClient, reason: Option<String>) {
-//!         println!("Connection closed: {:?}", reason);
+//!     fn on_connection_closed(&mut self, _client: &mut S9BlockingWebSocketClient, info: CloseInfo) {
+//!         println!("Connection closed by {:?}: {:?}", info.initiated_by, info.reason);
 //!     }
 //!
 //!     fn on_error(&mut self, _client: &mut S9BlockingWebSocketClient, error: String) {
@@ -128,22 +129,17 @@
 //! ### Async Non-blocking Client (with channels)
 //!
 //! ```no_run
-//! use s9_websocket::{S9AsyncNonBlockingWebSocketClient, WebSocketEvent, ControlMessage, NonBlockingOptions};
+//! use s9_websocket::{S9AsyncClientBuilder, WebSocketEvent, ControlMessage};
 //! use std::time::Duration;
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
-//! // Configure with 10ms sleep between reads
-//! let options = NonBlockingOptions::new()
-//!     .spin_wait_duration(Some(Duration::from_millis(10)))?;
-//!
-//! // Connect to WebSocket server
-//! let mut client = S9AsyncNonBlockingWebSocketClient::connect(
-//!     "wss://echo.websocket.org",
-//!     options
-//! )?;
+//! // Connect to WebSocket server, configured with 10ms sleep between reads
+//! let mut client = S9AsyncClientBuilder::new()
+//!     .spin_wait_duration(Some(Duration::from_millis(10)))
+//!     .connect("wss://echo.websocket.org")?;
 //!
 //! // Start the event loop (spawns thread)
-//! let _handle = client.run()?;
+//! client.run()?;
 //!
 //! // Send a message via control channel
 //! client.control_tx.send(ControlMessage::SendText("Hello!".to_string()))?;
@@ -155,7 +151,7 @@
 //!             println!("Received: {}", String::from_utf8_lossy(&data));
 //!             client.control_tx.send(ControlMessage::Close())?;
 //!         },
-//!         Ok(WebSocketEvent::Quit) => break,
+//!         Ok(WebSocketEvent::Quit(_)) => break,
 //!         _ => {}
 //!     }
 //! }
@@ -189,10 +185,11 @@
 //!    - `Some(Duration::from_millis(1-10))`: Good balance
 //!    - `Some(Duration::from_millis(50-100))`: Lower CPU, higher latency
 //!
-//! 2. **Enable TCP_NODELAY**: Reduce latency for small messages
+//! 2. **Keep TCP_NODELAY enabled**: Reduces latency for small messages and is enabled by default.
+//!    Disable it only to let Nagle's algorithm coalesce small messages:
 //!    ```no_run
 //!    # use s9_websocket::NonBlockingOptions;
-//!    let options = NonBlockingOptions::new().nodelay(true);
+//!    let options = NonBlockingOptions::new().nodelay(false);
 //!    ```
 //!
 //! ## Scalability
@@ -203,9 +200,21 @@
 //!
 //! ## Error Handling
 //!
-//! All fallible operations return [`S9Result<T>`], which is an alias for `Result<T, S9WebSocketError>`.
+//! All fallible operations return [`S9Result<T>`], which is an alias for `Result<T, S9WebSocketError>`,
+//! also available as [`s9_websocket::Result<T>`](Result).
 //! See [`S9WebSocketError`] for detailed error types.
 //!
+//! The error types are exported from the crate root and the [`error`] module, as are all connection types:
+//!
+//! ```
+//! use s9_websocket::error::{S9Result, S9WebSocketError};
+//! use s9_websocket::{CloseInfo, ConnectionStats, QuitReason};
+//!
+//! let result: S9Result<()> = Err(S9WebSocketError::SocketUnavailable);
+//! assert!(matches!(result, Err(s9_websocket::S9WebSocketError::SocketUnavailable)));
+//! # let _: Option<(CloseInfo, ConnectionStats, QuitReason)> = None;
+//! ```
+//!
 //! - **non-blocking**: A `WebSocketEvent::Quit` event is published after any error during reading messages from underlying WebSocket
 //! - **blocking**: The `S9WebSocketClientHandler::on_quit()` callback is called after any error during reading messages from underlying WebSocket
 //!
@@ -216,6 +225,7 @@
 //! ### Log levels:
 //! - **TRACE**: Detailed message content and connection details
 //! - **DEBUG**: Connection lifecycle events
+//! - **WARN**: Questionable configuration values
 //! - **ERROR**: Error conditions
 //!
 //! ```no_run
@@ -227,7 +237,9 @@
 //! For more examples and detailed documentation, see the [README on GitHub](https://github.com/AlexSilver9/s9_websocket).
 
 mod websocket;
-mod error;
+pub mod error;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 
 pub use websocket::*;
-pub use error::{S9Result, S9WebSocketError};
+pub use error::{Result, S9Result, S9ResultExt, S9WebSocketError};
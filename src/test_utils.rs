@@ -0,0 +1,212 @@
+//! Test utilities for applications and integration tests, requires the `test-utils` feature.
+//!
+//! [`EchoServer`] is a local WebSocket server echoing text and binary messages, [`RecordingHandler`]
+//! records all callbacks of the non-blocking and blocking clients as [`WebSocketEvent`]s.
+//!
+//! # Examples
+//!
+//! ```
+//! use s9_websocket::{NonBlockingOptions, S9NonBlockingWebSocketClient, WebSocketEvent};
+//! use s9_websocket::test_utils::{EchoServer, RecordingHandler};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = EchoServer::start()?;
+//! let mut client = S9NonBlockingWebSocketClient::connect(server.uri(), NonBlockingOptions::new())?;
+//! let mut handler = RecordingHandler::new().close_after(1);
+//!
+//! client.send_text_message("Hello!")?;
+//! client.run(&mut handler);
+//!
+//! assert_eq!(handler.texts(), vec!["Hello!"]);
+//! assert!(matches!(handler.events.last(), Some(WebSocketEvent::Quit(_))));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+use crate::websocket::{
+    CloseInfo, ConnectionInfo, QuitReason, S9BlockingWebSocketClient, S9NonBlockingWebSocketClient,
+    S9WebSocketClientHandler, WebSocketEvent,
+};
+
+// ============================================================================
+// EchoServer - Local WebSocket server for tests
+// ============================================================================
+
+/// Local WebSocket server echoing text and binary messages.
+///
+/// Binds `127.0.0.1` on a free port and handles every connection on its own thread. Pings are answered
+/// with pongs and close frames are echoed by tungstenite. The server stops accepting connections on drop.
+pub struct EchoServer {
+    addr: SocketAddr,
+    stopped: Arc<AtomicBool>,
+    accept_handle: Option<JoinHandle<()>>,
+}
+
+impl EchoServer {
+    /// Binds a free local port and starts accepting connections on a background thread.
+    pub fn start() -> io::Result<EchoServer> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let accept_stopped = Arc::clone(&stopped);
+        let accept_handle = thread::Builder::new()
+            .name("echo-server".to_string())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    if accept_stopped.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        thread::spawn(move || echo(stream));
+                    }
+                }
+            })?;
+
+        Ok(EchoServer { addr, stopped, accept_handle: Some(accept_handle) })
+    }
+
+    /// Returns the local address the server is bound to.
+    #[inline]
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the `ws://` URI to connect to the server.
+    #[inline]
+    pub fn uri(&self) -> String {
+        format!("ws://{}", self.addr)
+    }
+}
+
+impl Drop for EchoServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        // Wake up the blocking accept
+        let _ = TcpStream::connect(self.addr);
+        if let Some(accept_handle) = self.accept_handle.take() {
+            let _ = accept_handle.join();
+        }
+    }
+}
+
+/// Echoes text and binary messages until the connection is closed
+fn echo(stream: TcpStream) {
+    let Ok(mut socket) = tungstenite::accept(stream) else { return };
+    loop {
+        match socket.read() {
+            Ok(message) if message.is_text() || message.is_binary() => {
+                if socket.send(message).is_err() {
+                    break;
+                }
+            },
+            Ok(_) => {},
+            Err(_) => break,
+        }
+    }
+}
+
+// ============================================================================
+// RecordingHandler - Handler recording all callbacks
+// ============================================================================
+
+/// Handler recording the callbacks of [`S9NonBlockingWebSocketClient`] and [`S9BlockingWebSocketClient`].
+///
+/// Each callback is recorded as the [`WebSocketEvent`] the async client would publish for it, so the same
+/// assertions work for all clients. `on_poll` and `on_idle` are not recorded.
+#[derive(Debug, Default)]
+pub struct RecordingHandler {
+    /// Recorded events in the order of the callbacks
+    pub events: Vec<WebSocketEvent>,
+    close_after: Option<usize>,
+    received: usize,
+}
+
+impl RecordingHandler {
+    /// Creates a handler recording all callbacks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calls `close()` on the client once `messages` text or binary messages have been received.
+    pub fn close_after(mut self, messages: usize) -> Self {
+        self.close_after = Some(messages);
+        self
+    }
+
+    /// Returns the received text messages.
+    pub fn texts(&self) -> Vec<String> {
+        self.events.iter()
+            .filter_map(|event| match event {
+                WebSocketEvent::TextMessage(data) => Some(String::from_utf8_lossy(data).into_owned()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the [`CloseInfo`] of the recorded `on_connection_closed`, if any.
+    pub fn close_info(&self) -> Option<&CloseInfo> {
+        self.events.iter().find_map(|event| match event {
+            WebSocketEvent::ConnectionClosed(info) => Some(info),
+            _ => None,
+        })
+    }
+
+    /// Counts a received message and returns `true` if the client should be closed now
+    fn received_message(&mut self) -> bool {
+        self.received += 1;
+        self.close_after == Some(self.received)
+    }
+}
+
+macro_rules! impl_recording_handler {
+    ($client:ty) => {
+        impl S9WebSocketClientHandler<$client> for RecordingHandler {
+            fn on_activated(&mut self, _client: &mut $client, info: &ConnectionInfo) {
+                self.events.push(WebSocketEvent::Activated(info.clone()));
+            }
+
+            fn on_text_message(&mut self, client: &mut $client, data: &[u8]) {
+                self.events.push(WebSocketEvent::TextMessage(data.to_vec()));
+                if self.received_message() {
+                    client.close();
+                }
+            }
+
+            fn on_binary_message(&mut self, client: &mut $client, data: &[u8]) {
+                self.events.push(WebSocketEvent::BinaryMessage(data.to_vec()));
+                if self.received_message() {
+                    client.close();
+                }
+            }
+
+            fn on_ping(&mut self, _client: &mut $client, data: &[u8]) {
+                self.events.push(WebSocketEvent::Ping(data.to_vec()));
+            }
+
+            fn on_pong(&mut self, _client: &mut $client, data: &[u8]) {
+                self.events.push(WebSocketEvent::Pong(data.to_vec()));
+            }
+
+            fn on_connection_closed(&mut self, _client: &mut $client, info: CloseInfo) {
+                self.events.push(WebSocketEvent::ConnectionClosed(info));
+            }
+
+            fn on_error(&mut self, _client: &mut $client, error: String) {
+                self.events.push(WebSocketEvent::Error(error));
+            }
+
+            fn on_quit(&mut self, _client: &mut $client, reason: QuitReason) {
+                self.events.push(WebSocketEvent::Quit(reason));
+            }
+        }
+    };
+}
+
+impl_recording_handler!(S9NonBlockingWebSocketClient);
+impl_recording_handler!(S9BlockingWebSocketClient);
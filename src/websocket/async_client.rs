@@ -1,12 +1,18 @@
 use std::collections::HashMap;
+use std::fmt;
 use std::net::TcpStream;
+use std::io;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread::{self, JoinHandle};
-use crossbeam_channel::{unbounded, Receiver, Sender};
+use std::time::{Duration, Instant};
+use crossbeam_channel::{bounded, unbounded, Receiver, Sender, TrySendError};
 use tungstenite::stream::MaybeTlsStream;
 use tungstenite::{Message, WebSocket};
 use crate::error::{S9Result, S9WebSocketError};
-use super::options::NonBlockingOptions;
-use super::types::{WebSocketEvent, ControlMessage};
+use super::channel::{ChannelBackend, ControlSender, CrossbeamChannelBackend, ChannelReceiver, ChannelSender, RecvTimeoutError, SendError};
+use super::options::{EventFilter, NonBlockingOptions};
+use super::types::{ChannelStats, ClosedBy, ConnectionInfo, ConnectionStats, WebSocketEvent, ControlMessage, QuitReason};
 use super::types::{send_or_break, send_or_log};
 use super::shared;
 
@@ -14,34 +20,69 @@ use super::shared;
 // S9AsyncNonBlockingWebSocketClient - Async client with channels
 // ============================================================================
 
-pub struct S9AsyncNonBlockingWebSocketClient {
+/// Interval for checking the event loop thread while waiting for a graceful shutdown
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Time the event loop thread gets to stop after a graceful shutdown timed out and aborted it
+const SHUTDOWN_ABORT_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+pub struct S9AsyncNonBlockingWebSocketClient<B: ChannelBackend = CrossbeamChannelBackend> {
     socket: Option<WebSocket<MaybeTlsStream<TcpStream>>>,
     options: NonBlockingOptions,
-    pub control_tx: Sender<ControlMessage>,
+    /// Sends control messages to the event loop thread
+    pub control_tx: ControlSender,
     control_rx: Receiver<ControlMessage>,
-    event_tx: Sender<WebSocketEvent>,
-    pub event_rx: Receiver<WebSocketEvent>,
+    event_tx: B::Sender,
+    /// Receives events from the event loop thread, an [`EventReceiver`](crate::EventReceiver) with the default backend
+    pub event_rx: B::Receiver,
+    join_handle: Option<JoinHandle<()>>,
+    stats: Arc<shared::StatsCounters>,
+    connected_at: Option<Instant>,
+    connection_info: ConnectionInfo,
+    abort: Arc<AtomicBool>,
 }
 
 impl S9AsyncNonBlockingWebSocketClient {
     /// Connects to a WebSocket server and prepares for async operation.
     ///
     /// Creates a client ready to spawn a background thread via `run()`.
-    /// The connection supports both `ws://` and `wss://` protocols.
-    pub fn connect(uri: &str, options: NonBlockingOptions)-> S9Result<S9AsyncNonBlockingWebSocketClient> {
-        Self::connect_with_headers(uri, &HashMap::new(), options)
+    /// The connection supports both `ws://` and `wss://` protocols. The URI can be passed as `&str`, `String`, `Arc<str>` etc.
+    ///
+    /// **Deprecated**: Prefer [`S9AsyncClientBuilder`](crate::S9AsyncClientBuilder), which configures
+    /// the options and channels fluently before connecting.
+    pub fn connect(uri: impl AsRef<str>, options: NonBlockingOptions) -> S9Result<S9AsyncNonBlockingWebSocketClient> {
+        Self::connect_with_headers(uri, HashMap::<String, String>::new(), options)
     }
 
     /// Connects to a WebSocket server with custom HTTP headers.
     ///
     /// Allows setting custom headers (e.g., Authorization) during the WebSocket handshake.
-    pub fn connect_with_headers(uri: &str, headers: &HashMap<String, String>, options: NonBlockingOptions) -> S9Result<S9AsyncNonBlockingWebSocketClient> {
-        let (mut socket, _response) = shared::connect_socket(uri, headers)?;
+    /// Accepts any key/value pairs, e.g. a `&HashMap<String, String>` or `[("Authorization", "Bearer token")]`.
+    pub fn connect_with_headers(uri: impl AsRef<str>, headers: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>, options: NonBlockingOptions) -> S9Result<S9AsyncNonBlockingWebSocketClient> {
+        Self::connect_with_backend(uri, headers, options)
+    }
+}
+
+impl<B: ChannelBackend> S9AsyncNonBlockingWebSocketClient<B> {
+    /// Connects to a WebSocket server using the event channel of the [`ChannelBackend`] `B`.
+    ///
+    /// Same as [`connect_with_headers()`](S9AsyncNonBlockingWebSocketClient::connect_with_headers),
+    /// which uses the default [`CrossbeamChannelBackend`].
+    pub fn connect_with_backend(uri: impl AsRef<str>, headers: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>, options: NonBlockingOptions) -> S9Result<S9AsyncNonBlockingWebSocketClient<B>> {
+        let uri = uri.as_ref();
+        let headers = shared::collect_headers(headers);
+        let connect_result = shared::connect_socket(uri, &headers, &options)?;
+        let connection_info = shared::connection_info(uri, &connect_result.response);
+        let stats = shared::StatsCounters::new(connect_result.connect_duration());
+        let mut socket = connect_result.socket;
 
         shared::configure_non_blocking(&mut socket, &options)?;
 
-        let (control_tx, control_rx) = unbounded::<ControlMessage>();
-        let (event_tx, event_rx) = unbounded::<WebSocketEvent>();
+        let (control_tx, control_rx) = match options.control_capacity {
+            Some(capacity) => bounded::<ControlMessage>(capacity),
+            None => unbounded::<ControlMessage>(),
+        };
+        let (event_tx, event_rx) = B::channel(options.event_capacity);
 
         Ok(S9AsyncNonBlockingWebSocketClient {
             socket: Some(socket),
@@ -49,10 +90,32 @@ impl S9AsyncNonBlockingWebSocketClient {
             control_tx,
             control_rx,
             event_tx,
-            event_rx
+            event_rx,
+            join_handle: None,
+            stats: Arc::new(stats),
+            connected_at: Some(Instant::now()),
+            connection_info,
+            abort: Arc::new(AtomicBool::new(false)),
         })
     }
 
+    /// Returns the details of the established connection.
+    ///
+    /// Same as carried by [`WebSocketEvent::Activated`].
+    #[inline]
+    pub fn connection_info(&self) -> &ConnectionInfo {
+        &self.connection_info
+    }
+
+    /// Returns how long the connection has been established.
+    ///
+    /// Measured from the completed WebSocket handshake in [`connect_with_headers()`](Self::connect_with_headers).
+    /// Remains available after [`run()`](Self::run), as it is not moved to the event loop thread.
+    #[inline]
+    pub fn connection_age(&self) -> Option<Duration> {
+        self.connected_at.map(|connected_at| connected_at.elapsed())
+    }
+
     /// Returns a reference to the underlying WebSocket if it hasn't been moved to the event loop thread yet.
     ///
     /// This provides low-level access to the tungstenite WebSocket for advanced use cases.
@@ -73,120 +136,574 @@ impl S9AsyncNonBlockingWebSocketClient {
         self.socket.as_mut()
     }
 
+    /// Queues a text message to be sent by the event loop thread.
+    ///
+    /// Blocks while a bounded control channel is full, see [`try_send_text()`](Self::try_send_text) to fail instead.
+    /// Returns [`S9WebSocketError::Closed`] if the event loop thread is gone.
+    #[inline]
+    pub fn send_text(&self, text: &str) -> S9Result<()> {
+        self.send_control(ControlMessage::SendText(text.to_string()))
+    }
+
+    /// Queues a text message to be sent by the event loop thread without blocking.
+    ///
+    /// Returns [`S9WebSocketError::SendQueueFull`] if the control channel is full, or
+    /// [`S9WebSocketError::Closed`] if the event loop thread is gone.
+    #[inline]
+    pub fn try_send_text(&self, text: &str) -> S9Result<()> {
+        self.try_send_control(ControlMessage::SendText(text.to_string()))
+    }
+
+    /// Queues a binary message to be sent by the event loop thread.
+    ///
+    /// Blocks while a bounded control channel is full, see [`try_send_binary()`](Self::try_send_binary) to fail instead.
+    /// Returns [`S9WebSocketError::Closed`] if the event loop thread is gone.
+    #[inline]
+    pub fn send_binary(&self, data: Vec<u8>) -> S9Result<()> {
+        self.send_control(ControlMessage::SendBinary(data))
+    }
+
+    /// Queues a binary message to be sent by the event loop thread without blocking.
+    ///
+    /// Returns [`S9WebSocketError::SendQueueFull`] if the control channel is full, or
+    /// [`S9WebSocketError::Closed`] if the event loop thread is gone.
+    #[inline]
+    pub fn try_send_binary(&self, data: Vec<u8>) -> S9Result<()> {
+        self.try_send_control(ControlMessage::SendBinary(data))
+    }
+
+    /// Requests a graceful close of the WebSocket connection with code `1000` (normal closure) from the event loop thread.
+    ///
+    /// Blocks while a bounded control channel is full, see [`try_close()`](Self::try_close) to fail instead.
+    /// Returns [`S9WebSocketError::Closed`] if the event loop thread is gone.
+    #[inline]
+    pub fn close(&self) -> S9Result<()> {
+        self.send_control(ControlMessage::Close())
+    }
+
+    /// Requests a graceful close of the WebSocket connection from the event loop thread without blocking.
+    ///
+    /// Returns [`S9WebSocketError::SendQueueFull`] if the control channel is full, or
+    /// [`S9WebSocketError::Closed`] if the event loop thread is gone.
+    #[inline]
+    pub fn try_close(&self) -> S9Result<()> {
+        self.try_send_control(ControlMessage::Close())
+    }
+
+    /// Queues a control message without blocking.
+    ///
+    /// Returns [`S9WebSocketError::SendQueueFull`] if the control channel is full, or
+    /// [`S9WebSocketError::Closed`] if the event loop thread is gone.
+    #[inline]
+    pub fn try_send_control(&self, control_msg: ControlMessage) -> S9Result<()> {
+        self.control_tx.try_send(control_msg).map_err(|e| {
+            match e {
+                TrySendError::Full(_) => S9WebSocketError::SendQueueFull,
+                TrySendError::Disconnected(_) => {
+                    tracing::error!("Error sending control message: channel disconnected");
+                    S9WebSocketError::Closed { code: 1000, reason: String::new() }
+                }
+            }
+        })
+    }
+
+    /// Returns a snapshot of the message statistics updated by the event loop thread.
+    ///
+    /// See also [`NonBlockingOptions::stats_interval`] to receive snapshots periodically via `event_rx`.
+    #[inline]
+    pub fn stats(&self) -> ConnectionStats {
+        self.stats.snapshot()
+    }
+
+    /// Returns the number of queued messages and the capacities of the event and control channels.
+    ///
+    /// Useful to detect a backlog caused by a slow consumer of `event_rx` or a busy event loop thread.
+    #[inline]
+    pub fn channel_stats(&self) -> ChannelStats {
+        ChannelStats {
+            event_channel_len: ChannelReceiver::len(&self.event_rx),
+            control_channel_len: self.control_tx.len(),
+            event_channel_capacity: ChannelReceiver::capacity(&self.event_rx),
+            control_channel_capacity: self.control_tx.capacity(),
+        }
+    }
+
+    /// Requests the event loop thread to pause socket reads, see [`ControlMessage::PauseReads`].
+    ///
+    /// Blocks while a bounded control channel is full, see [`try_pause_reads()`](Self::try_pause_reads) to fail instead.
+    /// Returns [`S9WebSocketError::Closed`] if the event loop thread is gone.
+    #[inline]
+    pub fn pause_reads(&self) -> S9Result<()> {
+        self.send_control(ControlMessage::PauseReads())
+    }
+
+    /// Requests the event loop thread to pause socket reads without blocking.
+    ///
+    /// Returns [`S9WebSocketError::SendQueueFull`] if the control channel is full, or
+    /// [`S9WebSocketError::Closed`] if the event loop thread is gone.
+    #[inline]
+    pub fn try_pause_reads(&self) -> S9Result<()> {
+        self.try_send_control(ControlMessage::PauseReads())
+    }
+
+    /// Requests the event loop thread to resume socket reads, see [`ControlMessage::ResumeReads`].
+    ///
+    /// Blocks while a bounded control channel is full, see [`try_resume_reads()`](Self::try_resume_reads) to fail instead.
+    /// Returns [`S9WebSocketError::Closed`] if the event loop thread is gone.
+    #[inline]
+    pub fn resume_reads(&self) -> S9Result<()> {
+        self.send_control(ControlMessage::ResumeReads())
+    }
+
+    /// Requests the event loop thread to resume socket reads without blocking.
+    ///
+    /// Returns [`S9WebSocketError::SendQueueFull`] if the control channel is full, or
+    /// [`S9WebSocketError::Closed`] if the event loop thread is gone.
+    #[inline]
+    pub fn try_resume_reads(&self) -> S9Result<()> {
+        self.try_send_control(ControlMessage::ResumeReads())
+    }
+
     /// Spawns the background thread and starts processing WebSocket events.
     ///
-    /// Returns immediately with a `JoinHandle`. Send commands via `control_tx` and receive events via `event_rx`.
+    /// Returns immediately. Send commands via `control_tx` and receive events via `event_rx`.
     /// The socket is moved to the background thread and becomes unavailable for direct access.
+    /// Use [`wait()`](Self::wait) to join the background thread.
+    ///
+    /// Calling `run()` again returns [`S9WebSocketError::SocketUnavailable`] instead of panicking,
+    /// connect a new client to re-establish the connection.
     #[inline]
-    pub fn run(&mut self) -> S9Result<JoinHandle<()>> {
+    pub fn run(&mut self) -> S9Result<()> {
         // Take ownership of the socket to put it into the tread by replacing it with a dummy value
         // This is safe because we'll never use the original socket again after spawning
         let socket = self.socket.take();
         let mut socket = match socket {
             Some(s) => s,
             None => {
-                tracing::error!("Socket just consumed");
+                tracing::error!("run() already called; connect a new client to re-establish the connection");
                 return Err(S9WebSocketError::SocketUnavailable.into());
             },
         };
         let control_rx = self.control_rx.clone();
-        let event_tx = self.event_tx.clone();
+        let event_tx = EventPublisher {
+            event_tx: self.event_tx.clone(),
+            event_filter: self.options.event_filter.clone(),
+            error_sink: self.options.error_sink.clone(),
+        };
 
         if tracing::enabled!(tracing::Level::DEBUG) {
             tracing::debug!("Starting non-blocking event loop thread...");
         }
 
         let spin_wait_duration = self.options.shared.spin_wait_duration.clone();
+        let busy_spin_count = self.options.busy_spin_count;
+        let heartbeat_interval = self.options.heartbeat_interval;
+        let trace_level = self.options.shared.message_trace_level;
+        let stats_interval = self.options.stats_interval;
+        let stats = Arc::clone(&self.stats);
+        let connection_info = self.connection_info.clone();
+        let abort = Arc::clone(&self.abort);
+
+        let thread_name = self.options.thread_name.clone()
+            .or_else(|| shared::default_thread_name(&self.connection_info.uri));
+        let mut thread_builder = thread::Builder::new();
+        if let Some(thread_name) = thread_name {
+            thread_builder = thread_builder.name(thread_name);
+        }
 
-        let join_handle = thread::spawn(move || {
+        let join_handle = thread_builder.spawn(move || {
             if tracing::enabled!(tracing::Level::DEBUG) {
                 tracing::debug!("Starting event loop");
             }
 
             // Send Activate event before entering the main loop
-            send_or_log!(event_tx, "WebSocketEvent::Activated", WebSocketEvent::Activated);
+            send_or_log!(event_tx, "WebSocketEvent::Activated", WebSocketEvent::Activated(connection_info));
+
+            let mut last_heartbeat_at = Instant::now();
+            let mut last_stats_at = Instant::now();
+            let mut paused = false;
+            let mut close_initiated = false;
+            let mut idle_count: u32 = 0;
 
             loop {
+                // 0. Check for abort, bypassing the control channel
+                if abort.load(Ordering::Relaxed) {
+                    send_or_log!(event_tx, "WebSocketEvent::Quit on abort", WebSocketEvent::Quit(QuitReason::ForceQuit));
+                    break;
+                }
+
                 // 1. Check for control messages (non-blocking)
                 if let Ok(control_msg) = control_rx.try_recv() {
-                    match shared::handle_control_message(control_msg, &mut socket) {
-                        Ok(shared::ControlFlow::Continue) => {},
-                        Ok(shared::ControlFlow::Break) => {
-                            send_or_log!(event_tx, "WebSocketEvent::Quit on ControlMessage::ForceQuit", WebSocketEvent::Quit);
+                    match shared::handle_control_message(control_msg, &mut socket, &stats) {
+                        shared::ControlResult::Continue => {},
+                        shared::ControlResult::Closing => {
+                            close_initiated = true;
+                        },
+                        shared::ControlResult::Break => {
+                            send_or_log!(event_tx, "WebSocketEvent::Quit on ControlMessage::ForceQuit", WebSocketEvent::Quit(QuitReason::ForceQuit));
                             break;
                         },
-                        Err(error) => {
-                            send_or_break!(event_tx, "WebSocketEvent::Error on ControlMessage", WebSocketEvent::Error(error));
+                        shared::ControlResult::PauseReads => {
+                            if !paused {
+                                paused = true;
+                                send_or_break!(event_tx, "WebSocketEvent::ReadsPaused on ControlMessage::PauseReads", WebSocketEvent::ReadsPaused);
+                            }
+                        },
+                        shared::ControlResult::ResumeReads => {
+                            if paused {
+                                paused = false;
+                                send_or_break!(event_tx, "WebSocketEvent::ReadsResumed on ControlMessage::ResumeReads", WebSocketEvent::ReadsResumed);
+                            }
+                        },
+                        shared::ControlResult::SendError(error) => {
+                            send_or_break!(event_tx, "WebSocketEvent::Error on ControlMessage send error", WebSocketEvent::Error(format!("Error sending control message: {}", error)));
+                        },
+                        shared::ControlResult::ConfigError(error) => {
+                            send_or_break!(event_tx, "WebSocketEvent::Error on ControlMessage config error", WebSocketEvent::Error(format!("Invalid control message: {}", error)));
                         }
                     }
                 }
 
-                // 2. Try to read from socket (non-blocking)
-                match socket.read() {
-                    Ok(msg) => {
-                        match msg {
-                            Message::Text(message) => {
-                                shared::trace_on_text_message(&message);
-                                send_or_break!(event_tx, "WebSocketEvent::TextMessage on Message::Text", WebSocketEvent::TextMessage(message.as_bytes().to_vec()));
-                            },
-                            Message::Binary(bytes) => {
-                                shared::trace_on_binary_message(&bytes);
-                                send_or_break!(event_tx, "WebSocketEvent::BinaryMessage on Message::Binary", WebSocketEvent::BinaryMessage(bytes.to_vec()));
-                            },
-                            Message::Ping(bytes) => {
-                                shared::trace_on_ping_message(&bytes);
-                                send_or_break!(event_tx, "WebSocketEvent::Ping on Message::Ping", WebSocketEvent::Ping(bytes.to_vec()));
-                            },
-                            Message::Pong(bytes) => {
-                                shared::trace_on_pong_message(&bytes);
-                                send_or_break!(event_tx, "WebSocketEvent::Pong on Message::Pong", WebSocketEvent::Pong(bytes.to_vec()));
-                            },
-                            Message::Close(close_frame) => {
-                                shared::trace_on_close_frame(&close_frame);
-                                let reason = close_frame.map(|cf| cf.to_string());
-                                send_or_log!(event_tx, "WebSocketEvent::ConnectionClosed on Message::Close", WebSocketEvent::ConnectionClosed(reason));
-                                send_or_log!(event_tx, "WebSocketEvent::Quit on Message::Close", WebSocketEvent::Quit);
-                                break;
-                            },
-                            Message::Frame(_) => {
-                                shared::trace_on_frame();
-                                // No handling for frames until use case needs it
+                // 2. Try to read from socket (non-blocking), skipped while reads are paused
+                if !paused {
+                    match socket.read() {
+                        Ok(msg) => {
+                            idle_count = 0;
+                            match msg {
+                                Message::Text(message) => {
+                                    shared::trace_on_text_message(&message, trace_level.text);
+                                    stats.record_received(message.len());
+                                    send_or_break!(event_tx, "WebSocketEvent::TextMessage on Message::Text", WebSocketEvent::TextMessage(message.as_bytes().to_vec()));
+                                },
+                                Message::Binary(bytes) => {
+                                    shared::trace_on_binary_message(&bytes, trace_level.binary);
+                                    stats.record_received(bytes.len());
+                                    send_or_break!(event_tx, "WebSocketEvent::BinaryMessage on Message::Binary", WebSocketEvent::BinaryMessage(bytes.to_vec()));
+                                },
+                                Message::Ping(bytes) => {
+                                    shared::trace_on_ping_message(&bytes, trace_level.ping);
+                                    send_or_break!(event_tx, "WebSocketEvent::Ping on Message::Ping", WebSocketEvent::Ping(bytes.to_vec()));
+                                },
+                                Message::Pong(bytes) => {
+                                    shared::trace_on_pong_message(&bytes, trace_level.pong);
+                                    send_or_break!(event_tx, "WebSocketEvent::Pong on Message::Pong", WebSocketEvent::Pong(bytes.to_vec()));
+                                },
+                                Message::Close(close_frame) => {
+                                    shared::trace_on_close_frame(&close_frame);
+                                    let quit_reason = shared::quit_reason_from_close_frame(&close_frame);
+                                    let closed_by = if close_initiated { ClosedBy::Client } else { ClosedBy::Server };
+                                    let close_info = shared::close_info_from_close_frame(&close_frame, closed_by);
+                                    send_or_log!(event_tx, "WebSocketEvent::ConnectionClosed on Message::Close", WebSocketEvent::ConnectionClosed(close_info));
+                                    send_or_log!(event_tx, "WebSocketEvent::Quit on Message::Close", WebSocketEvent::Quit(quit_reason));
+                                    break;
+                                },
+                                Message::Frame(_) => {
+                                    shared::trace_on_frame(trace_level.frame);
+                                    // No handling for frames until use case needs it
+                                }
                             }
-                        }
-                    },
-                    Err(error) => {
-                        let (reason, should_break) = shared::handle_read_error(error);
-                        if let Some(error_msg) = reason {
-                            if should_break {
-                                let (context, event) = {
-                                    if shared::is_connection_closed_error(&error_msg) {
-                                        ("WebSocketEvent::ConnectionClosed  on Error::ConnectionClosed", WebSocketEvent::ConnectionClosed(Some(error_msg)))
-                                    } else {
-                                        ("WebSocketEvent::Error", WebSocketEvent::Error(error_msg))
-                                    }
-                                };
-                                send_or_log!(event_tx, context, event);
-                                send_or_break!(event_tx, "WebSocketEvent::Quit", WebSocketEvent::Quit);
-                                break;
+                        },
+                        Err(error) => {
+                            let (reason, should_break) = shared::handle_read_error(error);
+                            if let Some(error_msg) = reason {
+                                idle_count = 0;
+                                if should_break {
+                                    let (context, event, quit_reason) = {
+                                        if shared::is_connection_closed_error(&error_msg) {
+                                            ("WebSocketEvent::ConnectionClosed  on Error::ConnectionClosed", WebSocketEvent::ConnectionClosed(shared::close_info_from_error(error_msg.clone())), QuitReason::GracefulClose { code: None, reason: Some(error_msg) })
+                                        } else {
+                                            ("WebSocketEvent::Error", WebSocketEvent::Error(error_msg.clone()), QuitReason::Error(error_msg))
+                                        }
+                                    };
+                                    send_or_log!(event_tx, context, event);
+                                    send_or_break!(event_tx, "WebSocketEvent::Quit", WebSocketEvent::Quit(quit_reason));
+                                    break;
+                                }
+                            } else {
+                                idle_count = idle_count.saturating_add(1);
                             }
                         }
+                    };
+                } else {
+                    // Nothing is read while paused, count as idle to keep sleeping
+                    idle_count = idle_count.saturating_add(1);
+                }
+
+                // 3. Publish heartbeat if the interval elapsed
+                if let Some(interval) = heartbeat_interval {
+                    if last_heartbeat_at.elapsed() >= interval {
+                        last_heartbeat_at = Instant::now();
+                        send_or_break!(event_tx, "WebSocketEvent::Heartbeat", WebSocketEvent::Heartbeat(last_heartbeat_at));
                     }
-                };
+                }
 
-                // Optionally sleep to reduce CPU usage
+                // 4. Publish stats if the interval elapsed
+                if let Some(interval) = stats_interval {
+                    if last_stats_at.elapsed() >= interval {
+                        last_stats_at = Instant::now();
+                        send_or_break!(event_tx, "WebSocketEvent::Stats", WebSocketEvent::Stats(stats.snapshot()));
+                    }
+                }
+
+                // Optionally sleep to reduce CPU usage, after spinning busy_spin_count idle iterations
                 if let Some(duration) = spin_wait_duration {
-                    thread::sleep(duration);
+                    if idle_count >= busy_spin_count {
+                        thread::sleep(duration);
+                    }
+                }
+            }
+        })?;
+        self.join_handle = Some(join_handle);
+        Ok(())
+    }
+
+    /// Blocks until the background thread spawned by [`run()`](Self::run) has terminated.
+    ///
+    /// Returns immediately with `Ok(())` if `run()` was not called or the thread was already joined.
+    /// Returns `Err` with the panic payload if the background thread panicked.
+    pub fn wait(&mut self) -> thread::Result<()> {
+        match self.join_handle.take() {
+            Some(join_handle) => join_handle.join(),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns `true` while the background thread spawned by [`run()`](Self::run) is alive.
+    ///
+    /// Does not block. Returns `false` before `run()` was called, once the thread has exited
+    /// (also after a panic) and after it was joined via [`wait()`](Self::wait).
+    ///
+    /// ```
+    /// use s9_websocket::{NonBlockingOptions, S9AsyncNonBlockingWebSocketClient};
+    /// use std::net::TcpListener;
+    /// use std::thread;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let listener = TcpListener::bind("127.0.0.1:0")?;
+    /// # let addr = listener.local_addr()?;
+    /// # let server = thread::spawn(move || {
+    /// #     let (stream, _) = listener.accept().unwrap();
+    /// #     let mut socket = tungstenite::accept(stream).unwrap();
+    /// #     while socket.read().is_ok() {}
+    /// # });
+    /// let mut client = S9AsyncNonBlockingWebSocketClient::connect(&format!("ws://{}", addr), NonBlockingOptions::new())?;
+    /// assert!(!client.is_running());
+    ///
+    /// client.run()?;
+    /// assert!(client.is_running());
+    ///
+    /// client.abort();
+    /// client.wait().unwrap();
+    /// assert!(!client.is_running());
+    /// # server.join().unwrap();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.join_handle.as_ref().is_some_and(|join_handle| !join_handle.is_finished())
+    }
+
+    /// Waits up to `timeout` for the next event from the event loop thread.
+    ///
+    /// Convenience for `event_rx.recv_timeout(timeout)`.
+    #[inline]
+    pub fn recv_event_timeout(&self, timeout: Duration) -> Result<WebSocketEvent, RecvTimeoutError> {
+        ChannelReceiver::recv_timeout(&self.event_rx, timeout)
+    }
+
+    /// Stops the event loop thread immediately without sending a close frame.
+    ///
+    /// Unlike [`ControlMessage::ForceQuit`], this bypasses the control channel, so it also works
+    /// if the control channel is full. The event loop publishes [`WebSocketEvent::Quit`] with
+    /// [`QuitReason::ForceQuit`] on its next iteration.
+    #[inline]
+    pub fn abort(&self) {
+        self.abort.store(true, Ordering::Relaxed);
+    }
+
+    /// Closes the connection gracefully from outside the event loop and joins the event loop thread.
+    ///
+    /// Sends [`ControlMessage::Close`] and blocks until [`WebSocketEvent::Quit`] is received,
+    /// discarding all other events. If the server does not complete the close within `timeout`,
+    /// the event loop is stopped with [`abort()`](Self::abort) and an [`S9WebSocketError::Io`] error
+    /// of kind `TimedOut` is returned. If the thread does not stop within a grace period of one second after
+    /// the abort, e.g. because it is stuck in an event filter, it is not joined and the `TimedOut` error is
+    /// returned right away. If the close cannot be queued because the control channel is full,
+    /// the event loop is stopped with `abort()` right away and [`S9WebSocketError::SendQueueFull`] is
+    /// returned once the thread is joined within `timeout`.
+    ///
+    /// Returns immediately with `Ok(())` if [`run()`](Self::run) was not called or the thread was already joined.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use s9_websocket::S9AsyncClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = S9AsyncClientBuilder::new().connect("wss://echo.websocket.org")?;
+    /// client.run()?;
+    ///
+    /// // e.g. on a shutdown signal
+    /// client.graceful_shutdown(Duration::from_secs(5))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn graceful_shutdown(&mut self, timeout: Duration) -> S9Result<()> {
+        if self.join_handle.is_none() {
+            return Ok(());
+        }
+
+        let mut close_error = None;
+        match self.try_close() {
+            // Event loop thread already gone, just join it
+            Ok(()) | Err(S9WebSocketError::Closed { .. }) => {},
+            Err(e) => {
+                // Close frame cannot be queued, stop the event loop without it
+                if tracing::enabled!(tracing::Level::WARN) {
+                    tracing::warn!("Graceful shutdown could not send close: {}, aborting event loop", e);
+                }
+                self.abort();
+                close_error = Some(e);
+            },
+        }
+
+        let deadline = Instant::now() + timeout;
+        let mut timed_out = false;
+        // Poll in slices, as the Quit event may be consumed by another receiver of the event channel
+        while self.is_running() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                if tracing::enabled!(tracing::Level::WARN) {
+                    tracing::warn!("Graceful shutdown timed out after {:?}, aborting event loop", timeout);
+                }
+                timed_out = true;
+                self.abort();
+                // Keep draining, the event loop may be blocked on a full event channel
+                let grace_deadline = Instant::now() + SHUTDOWN_ABORT_GRACE_PERIOD;
+                while self.is_running() {
+                    if Instant::now() >= grace_deadline {
+                        // Keep the join handle, so dropping the client warns about the running thread
+                        tracing::error!("Event loop thread did not stop within {:?} after abort, not joining it", SHUTDOWN_ABORT_GRACE_PERIOD);
+                        return Err(S9WebSocketError::Io(io::Error::new(io::ErrorKind::TimedOut, "Graceful shutdown timed out")));
+                    }
+                    let _ = self.recv_event_timeout(SHUTDOWN_POLL_INTERVAL);
+                }
+                break;
+            }
+            match self.recv_event_timeout(remaining.min(SHUTDOWN_POLL_INTERVAL)) {
+                Ok(WebSocketEvent::Quit(_)) => break,
+                Ok(_) | Err(RecvTimeoutError::Timeout) => {},
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        self.wait().map_err(|_| {
+            tracing::error!("Event loop thread panicked during graceful shutdown");
+            S9WebSocketError::Io(io::Error::other("Event loop thread panicked"))
+        })?;
+
+        if timed_out {
+            return Err(S9WebSocketError::Io(io::Error::new(io::ErrorKind::TimedOut, "Graceful shutdown timed out")));
+        }
+        match close_error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Queues a control message, blocking while a bounded control channel is full
+    #[inline]
+    fn send_control(&self, control_msg: ControlMessage) -> S9Result<()> {
+        self.control_tx.send(control_msg).map_err(|_| {
+            tracing::error!("Error sending control message: channel disconnected");
+            S9WebSocketError::Closed { code: 1000, reason: String::new() }
+        })
+    }
+}
+
+/// Event channel sender applying the optional event filter before publishing
+/// and forwarding errors to the optional error sink
+struct EventPublisher<S: ChannelSender> {
+    event_tx: S,
+    event_filter: Option<EventFilter>,
+    error_sink: Option<Sender<String>>,
+}
+
+impl<S: ChannelSender> EventPublisher<S> {
+    #[inline]
+    fn send(&self, event: WebSocketEvent) -> Result<(), SendError<WebSocketEvent>> {
+        if let (Some(error_sink), WebSocketEvent::Error(error)) = (&self.error_sink, &event) {
+            // Never block the event loop on the error sink
+            if let Err(e) = error_sink.try_send(error.clone()) {
+                if tracing::enabled!(tracing::Level::WARN) {
+                    tracing::warn!("Dropped error for error sink: {}", e);
                 }
             }
-        });
-        Ok(join_handle)
+        }
+        if let Some(EventFilter(filter)) = &self.event_filter {
+            // The filter also observes Quit, which is published regardless of its result
+            if !filter(&event) && !matches!(event, WebSocketEvent::Quit(_)) {
+                if tracing::enabled!(tracing::Level::TRACE) {
+                    tracing::trace!(payload_len = event.payload_len(), "Event dropped by filter: {}", event);
+                }
+                return Ok(());
+            }
+        }
+        self.event_tx.send(event)
     }
 }
 
-impl Drop for S9AsyncNonBlockingWebSocketClient {
+/// Shows whether the socket is still present, the fill levels of the channels and the options.
+///
+/// The socket internals are not shown, `socket` is `None` once [`run()`](S9AsyncNonBlockingWebSocketClient::run)
+/// moved it to the event loop thread.
+///
+/// ```
+/// use s9_websocket::{NonBlockingOptions, S9AsyncNonBlockingWebSocketClient};
+/// use std::net::TcpListener;
+/// use std::thread;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let listener = TcpListener::bind("127.0.0.1:0")?;
+/// # let addr = listener.local_addr()?;
+/// # let server = thread::spawn(move || {
+/// #     let (stream, _) = listener.accept().unwrap();
+/// #     let mut socket = tungstenite::accept(stream).unwrap();
+/// #     while socket.read().is_ok() {}
+/// # });
+/// let client = S9AsyncNonBlockingWebSocketClient::connect(&format!("ws://{}", addr), NonBlockingOptions::new())?;
+///
+/// let debug = format!("{:?}", client);
+/// assert!(debug.starts_with("S9AsyncNonBlockingWebSocketClient { socket: Some(..), control_channel_len: 0, event_channel_len: Some(0), options: NonBlockingOptions {"));
+/// # drop(client);
+/// # server.join().unwrap();
+/// # Ok(())
+/// # }
+/// ```
+impl<B: ChannelBackend> fmt::Debug for S9AsyncNonBlockingWebSocketClient<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let socket = if self.socket.is_some() { "Some(..)" } else { "None" };
+        let channel_stats = self.channel_stats();
+        f.debug_struct("S9AsyncNonBlockingWebSocketClient")
+            .field("socket", &format_args!("{}", socket))
+            .field("control_channel_len", &channel_stats.control_channel_len)
+            .field("event_channel_len", &channel_stats.event_channel_len)
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+impl<B: ChannelBackend> Drop for S9AsyncNonBlockingWebSocketClient<B> {
     fn drop(&mut self) {
         if let Some(socket) = &mut self.socket {
             shared::close_websocket_with_logging(socket, "on Drop");
         }
+        if let Some(join_handle) = &self.join_handle {
+            if !join_handle.is_finished() && tracing::enabled!(tracing::Level::WARN) {
+                tracing::warn!("Client dropped while event loop thread is still running, call wait() to join it");
+            }
+        }
     }
 }
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use crossbeam_channel::Sender;
+use crate::error::S9Result;
+use super::async_client::S9AsyncNonBlockingWebSocketClient;
+use super::channel::ChannelBackend;
+use super::options::{MessageTraceLevel, OptionsBuilder};
+use super::types::WebSocketEvent;
+
+// ============================================================================
+// S9AsyncClientBuilder - Fluent builder for the async client
+// ============================================================================
+
+/// Fluent builder for [`S9AsyncNonBlockingWebSocketClient`].
+///
+/// Mirrors the [`OptionsBuilder`] methods, which also configure the channels and the event loop thread.
+/// Invalid values do not break the method chain, all options are validated by [`connect()`](Self::connect).
+///
+/// # Examples
+///
+/// ```no_run
+/// use s9_websocket::{S9AsyncClientBuilder, WebSocketEvent};
+/// use std::time::Duration;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut client = S9AsyncClientBuilder::new()
+///     .spin_wait_duration(Some(Duration::from_millis(10)))
+///     .nodelay(true)
+///     .event_capacity(1024)
+///     .control_capacity(64)
+///     .thread_name("ws-echo")
+///     .event_filter(|event| !matches!(event, WebSocketEvent::Ping(_) | WebSocketEvent::Pong(_)))
+///     .connect("wss://echo.websocket.org")?;
+///
+/// client.run()?;
+/// client.send_text("Hello!")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct S9AsyncClientBuilder {
+    options: OptionsBuilder,
+}
+
+impl S9AsyncClientBuilder {
+    /// Creates a new builder with default options.
+    pub fn new() -> Self {
+        Self {
+            options: OptionsBuilder::new(),
+        }
+    }
+
+    /// Sets the sleep duration between event loop iterations.
+    ///
+    /// See [`NonBlockingOptions::spin_wait_duration`](crate::NonBlockingOptions::spin_wait_duration).
+    pub fn spin_wait_duration(mut self, duration: Option<Duration>) -> Self {
+        self.options = self.options.spin_wait_duration(duration);
+        self
+    }
+
+    /// Sets the number of consecutive idle iterations spinning before sleeping.
+    ///
+    /// See [`NonBlockingOptions::busy_spin_count`](crate::NonBlockingOptions::busy_spin_count).
+    pub fn busy_spin_count(mut self, count: u32) -> Self {
+        self.options = self.options.busy_spin_count(count);
+        self
+    }
+
+    /// Enables or disables the `TCP_NODELAY` option for messages to be sent.
+    ///
+    /// See [`NonBlockingOptions::nodelay`](crate::NonBlockingOptions::nodelay).
+    pub fn nodelay(mut self, nodelay: bool) -> Self {
+        self.options = self.options.nodelay(nodelay);
+        self
+    }
+
+    /// Sets the TTL (Time To Live, # of hops) for the socket.
+    ///
+    /// See [`NonBlockingOptions::ttl`](crate::NonBlockingOptions::ttl).
+    pub fn ttl(mut self, ttl: Option<u32>) -> Self {
+        self.options = self.options.ttl(ttl);
+        self
+    }
+
+    /// Sets the target size of the write buffer in bytes.
+    ///
+    /// See [`NonBlockingOptions::write_buffer_size`](crate::NonBlockingOptions::write_buffer_size).
+    pub fn write_buffer_size(mut self, size: usize) -> Self {
+        self.options = self.options.write_buffer_size(size);
+        self
+    }
+
+    /// Sets the maximum size of the write buffer in bytes.
+    ///
+    /// See [`NonBlockingOptions::max_write_buffer_size`](crate::NonBlockingOptions::max_write_buffer_size).
+    pub fn max_write_buffer_size(mut self, size: usize) -> Self {
+        self.options = self.options.max_write_buffer_size(size);
+        self
+    }
+
+    /// Sets the tracing levels for logging received messages.
+    ///
+    /// See [`MessageTraceLevel`](crate::MessageTraceLevel).
+    pub fn message_trace_level(mut self, level: MessageTraceLevel) -> Self {
+        self.options = self.options.message_trace_level(level);
+        self
+    }
+
+    /// Sets the capacity of the event channel.
+    ///
+    /// See [`NonBlockingOptions::event_capacity`](crate::NonBlockingOptions::event_capacity).
+    pub fn event_capacity(mut self, capacity: usize) -> Self {
+        self.options = self.options.event_capacity(capacity);
+        self
+    }
+
+    /// Sets the capacity of the control channel.
+    ///
+    /// When the channel is full, the blocking send methods wait, the `try_*` methods return
+    /// [`SendQueueFull`](crate::S9WebSocketError::SendQueueFull).
+    /// See [`NonBlockingOptions::control_capacity`](crate::NonBlockingOptions::control_capacity).
+    pub fn control_capacity(mut self, capacity: usize) -> Self {
+        self.options = self.options.control_capacity(capacity);
+        self
+    }
+
+    /// Sets the name of the event loop thread.
+    ///
+    /// See [`NonBlockingOptions::thread_name`](crate::NonBlockingOptions::thread_name).
+    pub fn thread_name(mut self, name: impl Into<String>) -> Self {
+        self.options = self.options.thread_name(name);
+        self
+    }
+
+    /// Sets a filter deciding which events are published to the event channel.
+    ///
+    /// See [`NonBlockingOptions::event_filter`](crate::NonBlockingOptions::event_filter).
+    pub fn event_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&WebSocketEvent) -> bool + Send + Sync + 'static,
+    {
+        self.options = self.options.event_filter(filter);
+        self
+    }
+
+    /// Sets the interval for publishing heartbeat events.
+    ///
+    /// See [`NonBlockingOptions::heartbeat_interval`](crate::NonBlockingOptions::heartbeat_interval).
+    pub fn heartbeat_interval(mut self, interval: Option<Duration>) -> Self {
+        self.options = self.options.heartbeat_interval(interval);
+        self
+    }
+
+    /// Sets the interval for publishing statistics events.
+    ///
+    /// See [`NonBlockingOptions::stats_interval`](crate::NonBlockingOptions::stats_interval).
+    pub fn stats_interval(mut self, interval: Option<Duration>) -> Self {
+        self.options = self.options.stats_interval(interval);
+        self
+    }
+
+    /// Sets a secondary channel receiving the payload of every error event.
+    ///
+    /// See [`NonBlockingOptions::error_sink`](crate::NonBlockingOptions::error_sink).
+    pub fn error_sink(mut self, error_tx: Sender<String>) -> Self {
+        self.options = self.options.error_sink(error_tx);
+        self
+    }
+
+    /// Connects to a WebSocket server with all configured options.
+    ///
+    /// Returns [`S9WebSocketError::InvalidConfiguration`](crate::S9WebSocketError::InvalidConfiguration)
+    /// if any builder method received an invalid value.
+    pub fn connect(self, uri: impl AsRef<str>) -> S9Result<S9AsyncNonBlockingWebSocketClient> {
+        S9AsyncNonBlockingWebSocketClient::connect(uri, self.options.build()?)
+    }
+
+    /// Connects to a WebSocket server using the event channel of the [`ChannelBackend`] `B`.
+    ///
+    /// See [`S9AsyncNonBlockingWebSocketClient::connect_with_backend`].
+    pub fn connect_with_backend<B: ChannelBackend>(self, uri: impl AsRef<str>) -> S9Result<S9AsyncNonBlockingWebSocketClient<B>> {
+        S9AsyncNonBlockingWebSocketClient::<B>::connect_with_backend(uri, HashMap::<String, String>::new(), self.options.build()?)
+    }
+}
+
+impl Default for S9AsyncClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
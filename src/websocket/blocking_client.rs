@@ -1,11 +1,12 @@
 use std::collections::HashMap;
 use std::net::TcpStream;
 use std::thread;
+use std::time::{Duration, Instant};
 use tungstenite::stream::MaybeTlsStream;
 use tungstenite::{Error, Message, WebSocket};
-use crate::error::S9Result;
+use crate::error::{S9Result, S9WebSocketError};
 use super::options::BlockingOptions;
-use super::types::S9WebSocketClientHandler;
+use super::types::{S9WebSocketClientHandler, ClosedBy, ConnectionInfo, PollResult, QuitReason};
 use super::shared;
 
 // ============================================================================
@@ -15,30 +16,51 @@ use super::shared;
 pub struct S9BlockingWebSocketClient {
     socket: WebSocket<MaybeTlsStream<TcpStream>>,
     options: BlockingOptions,
+    uri: String,
+    headers: HashMap<String, String>,
     running: bool,
+    connected_at: Option<Instant>,
+    connection_info: ConnectionInfo,
+    close_initiated: bool,
+    quit_reason: Option<QuitReason>,
+    idle_tracker: shared::IdleTracker,
 }
 
 impl S9BlockingWebSocketClient{
     /// Connects to a WebSocket server with blocking I/O.
     ///
     /// Establishes a WebSocket connection using blocking socket operations.
-    /// The connection supports both `ws://` and `wss://` protocols.
-    pub fn connect(uri: &str, options: BlockingOptions,) -> S9Result<S9BlockingWebSocketClient> {
-        Self::connect_with_headers(uri, &HashMap::new(), options)
+    /// The connection supports both `ws://` and `wss://` protocols. The URI can be passed as `&str`, `String`, `Arc<str>` etc.
+    /// The options are checked with [`BlockingOptions::validate`] before connecting.
+    pub fn connect(uri: impl AsRef<str>, options: BlockingOptions) -> S9Result<S9BlockingWebSocketClient> {
+        Self::connect_with_headers(uri, HashMap::<String, String>::new(), options)
     }
 
     /// Connects to a WebSocket server with custom HTTP headers.
     ///
     /// Allows setting custom headers (e.g., Authorization, custom headers) during the WebSocket handshake.
-    pub fn connect_with_headers(uri: &str, headers: &HashMap<String, String>, options: BlockingOptions) -> S9Result<S9BlockingWebSocketClient> {
-        let (mut socket, _response) = shared::connect_socket(uri, headers)?;
+    /// Accepts any key/value pairs, e.g. a `&HashMap<String, String>` or `[("Authorization", "Bearer token")]`.
+    pub fn connect_with_headers(uri: impl AsRef<str>, headers: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>, options: BlockingOptions) -> S9Result<S9BlockingWebSocketClient> {
+        options.validate()?;
+        let uri = uri.as_ref();
+        let headers = shared::collect_headers(headers);
+        let connect_result = shared::connect_socket(uri, &headers, &options)?;
+        let connection_info = shared::connection_info(uri, &connect_result.response);
+        let mut socket = connect_result.socket;
 
         shared::configure_blocking(&mut socket, &options)?;
 
         Ok(S9BlockingWebSocketClient {
             socket,
             options,
+            uri: uri.to_string(),
+            headers,
             running: true,
+            connected_at: Some(Instant::now()),
+            connection_info,
+            close_initiated: false,
+            quit_reason: None,
+            idle_tracker: shared::IdleTracker::default(),
         })
     }
 
@@ -46,111 +68,185 @@ impl S9BlockingWebSocketClient{
     ///
     /// Blocks the calling thread and processes WebSocket messages through handler callbacks.
     /// Returns when the connection is closed or `force_quit()` is called from a handler.
+    /// The returned [`QuitReason`] describes why the event loop terminated. Once terminated, calling `run()`
+    /// again returns the same [`QuitReason`] right away without calling any handler callbacks.
     #[inline]
-    pub fn run<HANDLER>(&mut self, handler: &mut HANDLER)
+    pub fn run<HANDLER>(&mut self, handler: &mut HANDLER) -> QuitReason
     where
-        HANDLER: S9WebSocketClientHandler<Self>,
+        HANDLER: S9WebSocketClientHandler<Self> + ?Sized,
     {
         if tracing::enabled!(tracing::Level::DEBUG) {
             tracing::debug!("Starting event loop");
         }
 
+        if let Some(quit_reason) = &self.quit_reason {
+            // The connection already ended, don't activate the handler again
+            return quit_reason.clone();
+        }
+
+        self.idle_tracker.reset();
+
         // Notify activate before entering the main loop
-        handler.on_activated(self);
+        let connection_info = self.connection_info.clone();
+        handler.on_activated(self, &connection_info);
+
+        loop {
+            if let PollResult::Break(quit_reason) = self.poll_step(handler) {
+                return quit_reason;
+            }
 
-        while self.running {
+            // Optionally sleep to reduce CPU usage
+            if let Some(duration) = self.options.shared.spin_wait_duration {
+                if self.running {
+                    thread::sleep(duration);
+                }
+            }
+        }
+    }
+
+    /// Performs a single iteration of the event loop without sleeping.
+    ///
+    /// Calls [`on_poll`](S9WebSocketClientHandler::on_poll), reads exactly one message (blocking, up to the
+    /// [`read_timeout`](BlockingOptions::read_timeout) if set) and dispatches it to the handler.
+    /// Returns [`PollResult::Continue`] if a message was handled, [`PollResult::Idle`] if the read timed out
+    /// and [`PollResult::Break`] once the connection ended or [`force_quit()`](Self::force_quit) was called,
+    /// after calling [`on_quit`](S9WebSocketClientHandler::on_quit).
+    ///
+    /// Allows cooperative scheduling, e.g. in coroutine or fiber based runtimes, instead of calling
+    /// [`run()`](Self::run). [`on_activated`](S9WebSocketClientHandler::on_activated) is only called by `run()`.
+    /// Returns [`S9WebSocketError::Closed`] if the event loop has already terminated.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use s9_websocket::{BlockingOptions, PollResult, S9BlockingWebSocketClient, S9WebSocketClientHandler};
+    ///
+    /// struct MyHandler;
+    ///
+    /// impl S9WebSocketClientHandler<S9BlockingWebSocketClient> for MyHandler {}
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = S9BlockingWebSocketClient::connect("wss://echo.websocket.org", BlockingOptions::new())?;
+    /// let mut handler = MyHandler;
+    /// while let PollResult::Continue | PollResult::Idle = client.poll_once(&mut handler)? {
+    ///     // Yield to other fibers
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn poll_once<HANDLER>(&mut self, handler: &mut HANDLER) -> S9Result<PollResult>
+    where
+        HANDLER: S9WebSocketClientHandler<Self> + ?Sized,
+    {
+        if self.quit_reason.is_some() {
+            return Err(S9WebSocketError::Closed { code: 1000, reason: String::new() });
+        }
+        Ok(self.poll_step(handler))
+    }
+
+    /// Performs a single iteration of the event loop, shared by `run()` and `poll_once()`
+    fn poll_step<HANDLER>(&mut self, handler: &mut HANDLER) -> PollResult
+    where
+        HANDLER: S9WebSocketClientHandler<Self> + ?Sized,
+    {
+        if self.running {
             handler.on_poll(self);
+        }
 
-            let msg = match self.socket.read() {
-                Ok(msg) => msg,
-                Err(e) => {
-                    match e {
-                        Error::Io(ref err) if err.kind() == std::io::ErrorKind::WouldBlock => {
-                            if self.options.read_timeout.is_some() {
-                                // No data available, call on_idle and continue loop (expected in non-blocking mode using timeout)
-                                handler.on_idle(self);
-
-                                // Optionally sleep to reduce CPU usage
-                                if let Some(duration) = self.options.shared.spin_wait_duration {
-                                    thread::sleep(duration);
-                                }
-                                continue;
-                            } else {
-                                handler.on_error(self, format!("Error reading message: {}", e));
-                                handler.on_quit(self);
-                                break;
-                            }
-                        },
-                        Error::Io(ref err) if err.kind() == std::io::ErrorKind::TimedOut => {
-                            if self.options.read_timeout.is_some() {
-                                // No data available (e.g. Windows), call on_idle and continue loop (expected in non-blocking mode using timeout)
-                                handler.on_idle(self);
-
-                                // Optionally sleep to reduce CPU usage
-                                if let Some(duration) = self.options.shared.spin_wait_duration {
-                                    thread::sleep(duration);
-                                }
-                                continue;
-                            } else {
-                                handler.on_error(self, format!("Error reading message: {}", e));
-                                handler.on_quit(self);
-                                break;
-                            }
-                        }
-                        Error::ConnectionClosed => {
-                            handler.on_connection_closed(self, Some("Connection closed".to_string()));
-                            handler.on_quit(self);
-                            break;
-                        },
-                        _ => {
-                            handler.on_error(self, format!("Error reading message: {}", e));
-                            handler.on_quit(self);
-                            break;
+        // Exit before the potentially indefinitely blocking read if force_quit() was called, e.g. from on_poll
+        if !self.running {
+            return PollResult::Break(self.quit(handler, QuitReason::ForceQuit));
+        }
+
+        let trace_level = self.options.shared.message_trace_level;
+        let msg = match self.socket.read() {
+            Ok(msg) => {
+                self.idle_tracker.reset();
+                msg
+            },
+            Err(e) => {
+                match e {
+                    Error::Io(ref err) if matches!(err.kind(), std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut) => {
+                        // No data available (TimedOut e.g. on Windows), call on_idle
+                        let idle_info = self.idle_tracker.record_idle();
+                        handler.on_idle(self, &idle_info);
+
+                        // Expected when using a read timeout, otherwise unexpected and the handler had its chance to react
+                        if self.options.read_timeout.is_some() || !self.running {
+                            return PollResult::Idle;
                         }
-                    }
 
-                }
-            };
-
-            match msg {
-                Message::Text(message) => {
-                    shared::trace_on_text_message(&message);
-                    handler.on_text_message(self, message.as_bytes());
-                },
-                Message::Binary(bytes) => {
-                    shared::trace_on_binary_message(&bytes);
-                    handler.on_binary_message(self, &bytes);
-                },
-                Message::Ping(bytes) => {
-                    shared::trace_on_ping_message(&bytes);
-                    handler.on_ping(self, &bytes);
-                },
-                Message::Pong(bytes) => {
-                    shared::trace_on_pong_message(&bytes);
-                    handler.on_pong(self, &bytes);
-                },
-                Message::Close(close_frame) => {
-                    shared::trace_on_close_frame(&close_frame);
-                    let reason = close_frame.map(|cf| cf.to_string());
-                    handler.on_connection_closed(self, reason);
-                    handler.on_quit(self);
-                    break;
-                },
-                Message::Frame(_) => {
-                    shared::trace_on_frame();
+                        let error_msg = format!("Error reading message: {}", e);
+                        handler.on_error(self, error_msg.clone());
+                        let quit_reason = QuitReason::Error(error_msg);
+                        return PollResult::Break(self.quit(handler, quit_reason));
+                    },
+                    Error::ConnectionClosed => {
+                        let reason = "Connection closed".to_string();
+                        handler.on_connection_closed(self, shared::close_info_from_error(reason.clone()));
+                        let quit_reason = QuitReason::GracefulClose { code: None, reason: Some(reason) };
+                        return PollResult::Break(self.quit(handler, quit_reason));
+                    },
+                    _ => {
+                        let error_msg = format!("Error reading message: {}", e);
+                        handler.on_error(self, error_msg.clone());
+                        let quit_reason = QuitReason::Error(error_msg);
+                        return PollResult::Break(self.quit(handler, quit_reason));
+                    }
                 }
             }
+        };
 
-            // Optionally sleep to reduce CPU usage
-            if let Some(duration) = self.options.shared.spin_wait_duration {
-                thread::sleep(duration);
+        match msg {
+            Message::Text(message) => {
+                shared::trace_on_text_message(&message, trace_level.text);
+                handler.on_text_message(self, message.as_bytes());
+            },
+            Message::Binary(bytes) => {
+                shared::trace_on_binary_message(&bytes, trace_level.binary);
+                handler.on_binary_message(self, &bytes);
+            },
+            Message::Ping(bytes) => {
+                shared::trace_on_ping_message(&bytes, trace_level.ping);
+                handler.on_ping(self, &bytes);
+            },
+            Message::Pong(bytes) => {
+                shared::trace_on_pong_message(&bytes, trace_level.pong);
+                handler.on_pong(self, &bytes);
+            },
+            Message::Close(close_frame) => {
+                shared::trace_on_close_frame(&close_frame);
+                let quit_reason = shared::quit_reason_from_close_frame(&close_frame);
+                let close_info = shared::close_info_from_close_frame(&close_frame, self.closed_by());
+                handler.on_connection_closed(self, close_info);
+                return PollResult::Break(self.quit(handler, quit_reason));
+            },
+            Message::Frame(_) => {
+                shared::trace_on_frame(trace_level.frame);
             }
         }
+        PollResult::Continue
+    }
+
+    /// Calls `on_quit` once and stores the reason, later calls return the stored reason
+    #[inline]
+    fn quit<HANDLER>(&mut self, handler: &mut HANDLER, quit_reason: QuitReason) -> QuitReason
+    where
+        HANDLER: S9WebSocketClientHandler<Self> + ?Sized,
+    {
+        if let Some(quit_reason) = &self.quit_reason {
+            return quit_reason.clone();
+        }
+        self.quit_reason = Some(quit_reason.clone());
+        handler.on_quit(self, quit_reason.clone());
+        quit_reason
     }
 
     /// Sends a text message over the WebSocket connection.
     ///
     /// The message is immediately flushed to the socket.
+    /// Inside handler callbacks, wrap sends in [`send_or_handler_error!`](crate::send_or_handler_error)
+    /// to pass errors to [`S9WebSocketClientHandler::on_send_error`].
     #[inline]
     pub fn send_text_message(&mut self, text: &str) -> S9Result<()> {
         shared::send_text_message_to_websocket(&mut self.socket, text)
@@ -167,6 +263,7 @@ impl S9BlockingWebSocketClient{
     /// Sends a WebSocket ping frame.
     ///
     /// Can be used for keep-alive or latency measurement. The message is immediately flushed.
+    /// Returns [`S9WebSocketError::InvalidConfiguration`](crate::S9WebSocketError::InvalidConfiguration) if the payload exceeds 125 bytes.
     #[inline]
     pub fn send_ping(&mut self, data: Vec<u8>) -> S9Result<()> {
         shared::send_ping_to_websocket(&mut self.socket, data)
@@ -175,6 +272,7 @@ impl S9BlockingWebSocketClient{
     /// Sends a WebSocket pong frame.
     ///
     /// Typically used to respond to ping frames. The message is immediately flushed.
+    /// Returns [`S9WebSocketError::InvalidConfiguration`](crate::S9WebSocketError::InvalidConfiguration) if the payload exceeds 125 bytes.
     #[inline]
     pub fn send_pong(&mut self, data: Vec<u8>) -> S9Result<()> {
         shared::send_pong_to_websocket(&mut self.socket, data)
@@ -182,12 +280,36 @@ impl S9BlockingWebSocketClient{
 
     /// Initiates a graceful close of the WebSocket connection.
     ///
-    /// Sends a close frame to the server.
+    /// Sends a close frame with code `1000` (normal closure) to the server.
     /// The event loop continues until the server responds with a close frame or an error occurs.
     pub fn close(&mut self) {
+        self.close_initiated = true;
         shared::close_websocket_with_logging(&mut self.socket, "on close");
     }
 
+    /// Initiates a graceful close of the WebSocket connection with a custom close code and reason.
+    ///
+    /// Sends a close frame with the given code (e.g. `1000` for normal closure) to the server.
+    /// Code `0` sends a close frame without code and reason.
+    /// The event loop continues until the server responds with a close frame or an error occurs.
+    #[inline]
+    pub fn send_close(&mut self, code: u16, reason: &str) -> S9Result<()> {
+        self.close_initiated = true;
+        shared::send_close_to_websocket(&mut self.socket, code, reason)
+    }
+
+    /// Flushes buffered writes to the socket.
+    ///
+    /// Under load, tungstenite may keep writes in its internal write buffer between socket reads,
+    /// this writes them to the OS.
+    #[inline]
+    pub fn flush(&mut self) -> S9Result<()> {
+        self.socket.flush().map_err(|e| {
+            tracing::error!("Error flushing socket: {}", e);
+            S9WebSocketError::from(e)
+        })
+    }
+
     /// Immediately breaks the event loop without sending a close frame.
     ///
     /// Use this when you need to stop the client immediately, e.g. no close frame from server.
@@ -196,6 +318,43 @@ impl S9BlockingWebSocketClient{
         self.running = false;
     }
 
+    /// Returns the details of the established connection.
+    ///
+    /// Same as passed to [`S9WebSocketClientHandler::on_activated`].
+    #[inline]
+    pub fn connection_info(&self) -> &ConnectionInfo {
+        &self.connection_info
+    }
+
+    /// Returns the URI the client connected to.
+    ///
+    /// Useful for diagnostic display and as the target of a reconnect.
+    #[inline]
+    pub fn get_uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// Returns the custom HTTP headers sent during the WebSocket handshake.
+    #[inline]
+    pub fn get_headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    /// Returns how long the connection has been established.
+    ///
+    /// Measured from the completed WebSocket handshake in [`connect_with_headers()`](Self::connect_with_headers).
+    /// Useful for session expiry or auth token refresh in handler callbacks.
+    #[inline]
+    pub fn connection_age(&self) -> Option<Duration> {
+        self.connected_at.map(|connected_at| connected_at.elapsed())
+    }
+
+    /// Side that initiated the close, depending on whether the client sent a close frame first
+    #[inline]
+    fn closed_by(&self) -> ClosedBy {
+        if self.close_initiated { ClosedBy::Client } else { ClosedBy::Server }
+    }
+
     /// Returns a reference to the underlying WebSocket.
     ///
     /// This provides low-level access to the tungstenite WebSocket for advanced use cases.
@@ -216,6 +375,16 @@ impl S9BlockingWebSocketClient{
 
 }
 
+/// Access to the underlying WebSocket for utilities generic over both callback clients.
+///
+/// Same as [`get_socket()`](Self::get_socket).
+impl AsRef<WebSocket<MaybeTlsStream<TcpStream>>> for S9BlockingWebSocketClient {
+    #[inline]
+    fn as_ref(&self) -> &WebSocket<MaybeTlsStream<TcpStream>> {
+        self.get_socket()
+    }
+}
+
 impl Drop for S9BlockingWebSocketClient {
     fn drop(&mut self) {
         shared::close_websocket_with_logging(&mut self.socket, "on Drop");
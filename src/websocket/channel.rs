@@ -0,0 +1,221 @@
+use std::ops::Deref;
+use std::sync::mpsc;
+use std::time::Duration;
+use super::types::{ControlMessage, WebSocketEvent};
+
+pub use std::sync::mpsc::{RecvError, RecvTimeoutError, SendError};
+
+/// Sender of the control channel, type of [`control_tx`](crate::S9AsyncNonBlockingWebSocketClient::control_tx).
+pub type ControlSender = crossbeam_channel::Sender<ControlMessage>;
+
+/// Receiver of the event channel of the default [`CrossbeamChannelBackend`],
+/// type of [`event_rx`](crate::S9AsyncNonBlockingWebSocketClient::event_rx).
+pub type EventReceiver = crossbeam_channel::Receiver<WebSocketEvent>;
+
+// ============================================================================
+// ChannelBackend - Event channel of the async client
+// ============================================================================
+
+/// Channel implementation used by [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient)
+/// to deliver [`WebSocketEvent`]s from the event loop thread.
+///
+/// [`CrossbeamChannelBackend`] is the default. [`StdChannelBackend`] uses `std::sync::mpsc` and does not
+/// require `crossbeam-channel` as dependency of the caller.
+///
+/// # Examples
+///
+/// ```no_run
+/// use s9_websocket::{S9AsyncNonBlockingWebSocketClient, NonBlockingOptions, StdChannelBackend, WebSocketEvent};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut client = S9AsyncNonBlockingWebSocketClient::<StdChannelBackend>::connect_with_backend(
+///     "wss://echo.websocket.org", [("Authorization", "Bearer token")], NonBlockingOptions::new())?;
+/// client.run()?;
+///
+/// while let Ok(event) = client.event_rx.recv() {
+///     if let WebSocketEvent::Quit(_) = event {
+///         break;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait ChannelBackend {
+    /// Sending half, moved to the event loop thread
+    type Sender: ChannelSender;
+    /// Receiving half, exposed as [`event_rx`](crate::S9AsyncNonBlockingWebSocketClient::event_rx)
+    type Receiver: ChannelReceiver;
+
+    /// Creates a channel, bounded if a capacity is given and unbounded otherwise.
+    fn channel(capacity: Option<usize>) -> (Self::Sender, Self::Receiver);
+}
+
+/// Sending half of a [`ChannelBackend`].
+pub trait ChannelSender: Clone + Send + 'static {
+    /// Sends an event, blocking while a bounded channel is full.
+    ///
+    /// Fails if the receiving half was dropped.
+    fn send(&self, event: WebSocketEvent) -> Result<(), SendError<WebSocketEvent>>;
+}
+
+/// Receiving half of a [`ChannelBackend`].
+pub trait ChannelReceiver: Send {
+    /// Blocks until the next event is received.
+    ///
+    /// Fails if the event loop thread is gone and the channel is empty.
+    fn recv(&self) -> Result<WebSocketEvent, RecvError>;
+
+    /// Blocks up to `timeout` until the next event is received.
+    fn recv_timeout(&self, timeout: Duration) -> Result<WebSocketEvent, RecvTimeoutError>;
+
+    /// Returns the number of queued events, `None` if not supported by the channel.
+    fn len(&self) -> Option<usize>;
+
+    /// Returns `true` if no events are queued, `None` if the number of queued events is not supported by the channel.
+    fn is_empty(&self) -> Option<bool> {
+        self.len().map(|len| len == 0)
+    }
+
+    /// Returns the capacity of the channel, `None` if unbounded.
+    fn capacity(&self) -> Option<usize>;
+}
+
+// ============================================================================
+// CrossbeamChannelBackend
+// ============================================================================
+
+/// Default [`ChannelBackend`] based on `crossbeam-channel`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrossbeamChannelBackend;
+
+impl ChannelBackend for CrossbeamChannelBackend {
+    type Sender = crossbeam_channel::Sender<WebSocketEvent>;
+    type Receiver = EventReceiver;
+
+    fn channel(capacity: Option<usize>) -> (Self::Sender, Self::Receiver) {
+        match capacity {
+            Some(capacity) => crossbeam_channel::bounded(capacity),
+            None => crossbeam_channel::unbounded(),
+        }
+    }
+}
+
+impl ChannelSender for crossbeam_channel::Sender<WebSocketEvent> {
+    #[inline]
+    fn send(&self, event: WebSocketEvent) -> Result<(), SendError<WebSocketEvent>> {
+        crossbeam_channel::Sender::send(self, event).map_err(|e| SendError(e.into_inner()))
+    }
+}
+
+impl ChannelReceiver for EventReceiver {
+    #[inline]
+    fn recv(&self) -> Result<WebSocketEvent, RecvError> {
+        crossbeam_channel::Receiver::recv(self).map_err(|_| RecvError)
+    }
+
+    #[inline]
+    fn recv_timeout(&self, timeout: Duration) -> Result<WebSocketEvent, RecvTimeoutError> {
+        crossbeam_channel::Receiver::recv_timeout(self, timeout).map_err(|e| match e {
+            crossbeam_channel::RecvTimeoutError::Timeout => RecvTimeoutError::Timeout,
+            crossbeam_channel::RecvTimeoutError::Disconnected => RecvTimeoutError::Disconnected,
+        })
+    }
+
+    #[inline]
+    fn len(&self) -> Option<usize> {
+        Some(crossbeam_channel::Receiver::len(self))
+    }
+
+    #[inline]
+    fn capacity(&self) -> Option<usize> {
+        crossbeam_channel::Receiver::capacity(self)
+    }
+}
+
+// ============================================================================
+// StdChannelBackend
+// ============================================================================
+
+/// [`ChannelBackend`] based on `std::sync::mpsc`.
+///
+/// Bounded channels use [`mpsc::sync_channel`], unbounded channels [`mpsc::channel`].
+/// `std` channels don't expose the number of queued events, so [`ChannelReceiver::len`] always returns `None`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdChannelBackend;
+
+impl ChannelBackend for StdChannelBackend {
+    type Sender = StdEventSender;
+    type Receiver = StdEventReceiver;
+
+    fn channel(capacity: Option<usize>) -> (Self::Sender, Self::Receiver) {
+        match capacity {
+            Some(capacity) => {
+                let (sender, receiver) = mpsc::sync_channel(capacity);
+                (StdEventSender::Bounded(sender), StdEventReceiver { receiver, capacity: Some(capacity) })
+            },
+            None => {
+                let (sender, receiver) = mpsc::channel();
+                (StdEventSender::Unbounded(sender), StdEventReceiver { receiver, capacity: None })
+            },
+        }
+    }
+}
+
+/// Sending half of [`StdChannelBackend`].
+#[derive(Debug, Clone)]
+pub enum StdEventSender {
+    /// Sender of a bounded channel created by [`mpsc::sync_channel`]
+    Bounded(mpsc::SyncSender<WebSocketEvent>),
+    /// Sender of an unbounded channel created by [`mpsc::channel`]
+    Unbounded(mpsc::Sender<WebSocketEvent>),
+}
+
+impl ChannelSender for StdEventSender {
+    #[inline]
+    fn send(&self, event: WebSocketEvent) -> Result<(), SendError<WebSocketEvent>> {
+        match self {
+            StdEventSender::Bounded(sender) => sender.send(event),
+            StdEventSender::Unbounded(sender) => sender.send(event),
+        }
+    }
+}
+
+/// Receiving half of [`StdChannelBackend`].
+///
+/// Dereferences to [`mpsc::Receiver`], so e.g. `recv()`, `try_recv()` and `iter()` can be called directly.
+#[derive(Debug)]
+pub struct StdEventReceiver {
+    receiver: mpsc::Receiver<WebSocketEvent>,
+    capacity: Option<usize>,
+}
+
+impl Deref for StdEventReceiver {
+    type Target = mpsc::Receiver<WebSocketEvent>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.receiver
+    }
+}
+
+impl ChannelReceiver for StdEventReceiver {
+    #[inline]
+    fn recv(&self) -> Result<WebSocketEvent, RecvError> {
+        self.receiver.recv()
+    }
+
+    #[inline]
+    fn recv_timeout(&self, timeout: Duration) -> Result<WebSocketEvent, RecvTimeoutError> {
+        self.receiver.recv_timeout(timeout)
+    }
+
+    #[inline]
+    fn len(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+}
@@ -1,20 +1,26 @@
 // Public API modules
 pub mod types;
 pub mod options;
+pub mod channel;
 
 // Internal modules
 mod shared;
 
 // Client implementations
 mod async_client;
+mod async_client_builder;
 mod nonblocking_client;
+mod nonblocking_split;
 mod blocking_client;
 
 // Re-export public types
-pub use types::{S9WebSocketClientHandler, WebSocketEvent, ControlMessage};
-pub use options::{NonBlockingOptions, BlockingOptions};
+pub use types::{S9WebSocketClientHandler, ConnectionInfo, CloseInfo, ClosedBy, IdleInfo, QuitReason, PollResult, ConnectionStats, ChannelStats, WebSocketEvent, ControlMessage};
+pub use channel::{ControlSender, EventReceiver, ChannelBackend, ChannelSender, ChannelReceiver, CrossbeamChannelBackend, StdChannelBackend, StdEventSender, StdEventReceiver};
+pub use options::{NonBlockingOptions, OptionsBuilder, BlockingOptions, MessageTraceLevel};
 
 // Re-export client types
 pub use async_client::S9AsyncNonBlockingWebSocketClient;
+pub use async_client_builder::S9AsyncClientBuilder;
 pub use nonblocking_client::S9NonBlockingWebSocketClient;
+pub use nonblocking_split::{NonBlockingSender, NonBlockingReceiver};
 pub use blocking_client::S9BlockingWebSocketClient;
@@ -1,44 +1,101 @@
 use std::collections::HashMap;
 use std::net::TcpStream;
 use std::thread;
+use std::time::{Duration, Instant};
 use tungstenite::stream::MaybeTlsStream;
 use tungstenite::{Message, WebSocket};
-use crate::error::S9Result;
-use super::options::NonBlockingOptions;
-use super::types::S9WebSocketClientHandler;
+use crate::error::{S9Result, S9WebSocketError};
+use super::options::{MessageTraceLevel, NonBlockingOptions};
+use super::types::{S9WebSocketClientHandler, ClosedBy, ConnectionInfo, PollResult, QuitReason};
+use super::nonblocking_split::{self, NonBlockingReceiver, NonBlockingSender};
 use super::shared;
 
+/// Time to wait for the server's close frame after [`S9NonBlockingWebSocketClient::run_until_quit`] timed out
+const TIMEOUT_CLOSE_WAIT: Duration = Duration::from_secs(1);
+
 // ============================================================================
 // S9NonBlockingWebSocketClient - Pure non-blocking client with handler callbacks
 // ============================================================================
 
+/// Pure non-blocking WebSocket client with handler callbacks on the caller's thread.
+///
+/// # Thread Safety
+///
+/// The client is `Send`: it owns the underlying socket and may be moved to another thread,
+/// e.g. to run the event loop there. It is not meant to be shared, all methods take `&mut self`
+/// and [`run()`](Self::run) occupies the thread it is called on until the event loop terminates.
+/// No wrapper is needed to move the client across threads.
+///
+/// ```
+/// fn assert_send<T: Send>() {}
+/// assert_send::<s9_websocket::S9NonBlockingWebSocketClient>();
+/// ```
 pub struct S9NonBlockingWebSocketClient {
     socket: WebSocket<MaybeTlsStream<TcpStream>>,
     options: NonBlockingOptions,
+    uri: String,
+    headers: HashMap<String, String>,
     running: bool,
+    connected_at: Option<Instant>,
+    connection_info: ConnectionInfo,
+    close_initiated: bool,
+    quit_reason: Option<QuitReason>,
+    timed_out: bool,
+    deadline: Option<Instant>,
+    idle_tracker: shared::IdleTracker,
 }
 
 impl S9NonBlockingWebSocketClient {
     /// Connects to a WebSocket server with non-blocking I/O.
     ///
     /// Establishes a WebSocket connection using non-blocking socket operations.
-    /// The connection supports both `ws://` and `wss://` protocols.
-    pub fn connect(uri: &str, options: NonBlockingOptions) -> S9Result<S9NonBlockingWebSocketClient> {
-        Self::connect_with_headers(uri, &HashMap::new(), options)
+    /// The connection supports both `ws://` and `wss://` protocols. The URI can be passed as `&str`, `String`, `Arc<str>` etc.
+    pub fn connect(uri: impl AsRef<str>, options: NonBlockingOptions) -> S9Result<S9NonBlockingWebSocketClient> {
+        Self::connect_with_headers(uri, HashMap::<String, String>::new(), options)
     }
 
     /// Connects to a WebSocket server with custom HTTP headers.
     ///
     /// Allows setting custom headers (e.g., Authorization) during the WebSocket handshake.
-    pub fn connect_with_headers(uri: &str, headers: &HashMap<String, String>, options: NonBlockingOptions) -> S9Result<S9NonBlockingWebSocketClient> {
-        let (mut socket, _response) = shared::connect_socket(uri, headers)?;
+    /// Accepts any key/value pairs, e.g. a `&HashMap<String, String>` or `[("Authorization", "Bearer token")]`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use s9_websocket::{NonBlockingOptions, S9NonBlockingWebSocketClient};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uri = String::from("wss://api.example.com/ws");
+    /// let client = S9NonBlockingWebSocketClient::connect_with_headers(
+    ///     &uri,
+    ///     [("Authorization", "Bearer token123"), ("X-Custom-Header", "value")],
+    ///     NonBlockingOptions::new(),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn connect_with_headers(uri: impl AsRef<str>, headers: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>, options: NonBlockingOptions) -> S9Result<S9NonBlockingWebSocketClient> {
+        let uri = uri.as_ref();
+        let headers = shared::collect_headers(headers);
+        let connect_result = shared::connect_socket(uri, &headers, &options)?;
+        let connection_info = shared::connection_info(uri, &connect_result.response);
+        let mut socket = connect_result.socket;
 
         shared::configure_non_blocking(&mut socket, &options)?;
 
         Ok(S9NonBlockingWebSocketClient {
             socket,
             options,
+            uri: uri.to_string(),
+            headers,
             running: true,
+            connected_at: Some(Instant::now()),
+            connection_info,
+            close_initiated: false,
+            quit_reason: None,
+            timed_out: false,
+            deadline: None,
+            idle_tracker: shared::IdleTracker::default(),
         })
     }
 
@@ -46,80 +103,243 @@ impl S9NonBlockingWebSocketClient {
     ///
     /// Blocks the calling thread and processes WebSocket messages through handler callbacks.
     /// Returns when the connection is closed or `force_quit()` is called from a handler.
+    /// The returned [`QuitReason`] describes why the event loop terminated. Once terminated, calling `run()`
+    /// again returns the same [`QuitReason`] right away without calling any handler callbacks.
     #[inline]
-    pub fn run<HANDLER>(&mut self, handler: &mut HANDLER)
+    pub fn run<HANDLER>(&mut self, handler: &mut HANDLER) -> QuitReason
+    where
+        HANDLER: S9WebSocketClientHandler<Self> + ?Sized,
+    {
+        self.run_loop(handler, None)
+    }
+
+    /// Starts the non-blocking event loop like [`run()`](Self::run), but with a deadline of `Instant::now() + timeout`.
+    ///
+    /// Once the deadline has passed, a close frame with code `1001` and reason `"client timeout"` is sent.
+    /// The event loop keeps reading until the server's close frame arrives, but at most for one more second,
+    /// and then quits. Returns [`QuitReason::Timeout`] if the deadline was the cause, also passed to
+    /// [`on_quit`](S9WebSocketClientHandler::on_quit).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use s9_websocket::{NonBlockingOptions, QuitReason, S9NonBlockingWebSocketClient, S9WebSocketClientHandler};
+    /// use std::time::Duration;
+    ///
+    /// struct MyHandler;
+    ///
+    /// impl S9WebSocketClientHandler<S9NonBlockingWebSocketClient> for MyHandler {}
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = S9NonBlockingWebSocketClient::connect("wss://echo.websocket.org", NonBlockingOptions::new())?;
+    /// if client.run_until_quit(&mut MyHandler, Duration::from_secs(30)) == QuitReason::Timeout {
+    ///     println!("Session ended after 30 seconds");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn run_until_quit<HANDLER>(&mut self, handler: &mut HANDLER, timeout: Duration) -> QuitReason
     where
-        HANDLER: S9WebSocketClientHandler<Self>,
+        HANDLER: S9WebSocketClientHandler<Self> + ?Sized,
+    {
+        self.run_loop(handler, Some(Instant::now() + timeout))
+    }
+
+    /// Runs the event loop, closing the connection once the optional deadline has passed
+    fn run_loop<HANDLER>(&mut self, handler: &mut HANDLER, deadline: Option<Instant>) -> QuitReason
+    where
+        HANDLER: S9WebSocketClientHandler<Self> + ?Sized,
     {
         if tracing::enabled!(tracing::Level::DEBUG) {
             tracing::debug!("Starting event loop");
         }
 
+        if let Some(quit_reason) = &self.quit_reason {
+            // The connection already ended, don't activate the handler again
+            return quit_reason.clone();
+        }
+
+        self.timed_out = false;
+        self.deadline = deadline;
+        self.idle_tracker.reset();
+
         // Notify activate before entering the main loop
-        handler.on_activated(self);
-
-        while self.running {
-            handler.on_poll(self);
-
-            match self.socket.read() {
-                Ok(msg) => {
-                    match msg {
-                        Message::Text(message) => {
-                            shared::trace_on_text_message(&message);
-                            handler.on_text_message(self, message.as_bytes());
-                        },
-                        Message::Binary(bytes) => {
-                            shared::trace_on_binary_message(&bytes);
-                            handler.on_binary_message(self, &bytes);
-                        },
-                        Message::Ping(bytes) => {
-                            shared::trace_on_ping_message(&bytes);
-                            handler.on_ping(self, &bytes);
-                        },
-                        Message::Pong(bytes) => {
-                            shared::trace_on_pong_message(&bytes);
-                            handler.on_pong(self, &bytes);
-                        },
-                        Message::Close(close_frame) => {
-                            shared::trace_on_close_frame(&close_frame);
-                            let reason = close_frame.map(|cf| cf.to_string());
-                            handler.on_connection_closed(self, reason);
-                            handler.on_quit(self);
-                            break;
-                        },
-                        Message::Frame(_) => {
-                            shared::trace_on_frame();
-                        }
+        let connection_info = self.connection_info.clone();
+        handler.on_activated(self, &connection_info);
+
+        let busy_spin_count = self.options.busy_spin_count;
+        let mut idle_count: u32 = 0;
+
+        let quit_reason = loop {
+            match self.poll_step(handler) {
+                PollResult::Continue => idle_count = 0,
+                PollResult::Idle => idle_count = idle_count.saturating_add(1),
+                PollResult::Break(quit_reason) => break quit_reason,
+            }
+
+            // Optionally sleep to reduce CPU usage, after spinning busy_spin_count idle iterations
+            if let Some(duration) = self.options.shared.spin_wait_duration {
+                if idle_count >= busy_spin_count {
+                    thread::sleep(duration);
+                }
+            }
+        };
+
+        self.deadline = None;
+        quit_reason
+    }
+
+    /// Performs a single iteration of the event loop without sleeping.
+    ///
+    /// Calls [`on_poll`](S9WebSocketClientHandler::on_poll), reads at most one message from the
+    /// non-blocking socket and dispatches it to the handler. Returns [`PollResult::Idle`] if no data was
+    /// available, [`PollResult::Continue`] if a message was handled and [`PollResult::Break`] once the
+    /// connection ended or [`force_quit()`](Self::force_quit) was called, after calling
+    /// [`on_quit`](S9WebSocketClientHandler::on_quit).
+    ///
+    /// Allows to integrate the client into an existing event loop, e.g. of a game engine, instead of
+    /// calling [`run()`](Self::run). [`on_activated`](S9WebSocketClientHandler::on_activated) is only called by `run()`.
+    /// Returns [`S9WebSocketError::Closed`] if the event loop has already terminated.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use s9_websocket::{NonBlockingOptions, PollResult, S9NonBlockingWebSocketClient, S9WebSocketClientHandler};
+    ///
+    /// struct MyHandler;
+    ///
+    /// impl S9WebSocketClientHandler<S9NonBlockingWebSocketClient> for MyHandler {}
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = S9NonBlockingWebSocketClient::connect("wss://echo.websocket.org", NonBlockingOptions::new())?;
+    /// let mut handler = MyHandler;
+    /// loop {
+    ///     if let PollResult::Break(reason) = client.poll_once(&mut handler)? {
+    ///         println!("Quit: {:?}", reason);
+    ///         break;
+    ///     }
+    ///     // Update the rest of the application
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn poll_once<HANDLER>(&mut self, handler: &mut HANDLER) -> S9Result<PollResult>
+    where
+        HANDLER: S9WebSocketClientHandler<Self> + ?Sized,
+    {
+        if self.quit_reason.is_some() {
+            return Err(S9WebSocketError::Closed { code: 1000, reason: String::new() });
+        }
+        Ok(self.poll_step(handler))
+    }
+
+    /// Performs a single iteration of the event loop, shared by `run()` and `poll_once()`
+    fn poll_step<HANDLER>(&mut self, handler: &mut HANDLER) -> PollResult
+    where
+        HANDLER: S9WebSocketClientHandler<Self> + ?Sized,
+    {
+        if !self.running {
+            // Loop left via force_quit()
+            return PollResult::Break(self.quit(handler, QuitReason::ForceQuit));
+        }
+
+        handler.on_poll(self);
+
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                if self.timed_out {
+                    // No close frame from the server in time
+                    self.force_quit();
+                    return PollResult::Continue;
+                }
+                if tracing::enabled!(tracing::Level::DEBUG) {
+                    tracing::debug!("Event loop deadline passed, closing connection");
+                }
+                self.timed_out = true;
+                crate::send_or_handler_error!(self, handler, self.send_close(1001, "client timeout")
+                    .inspect_err(|e| tracing::error!("Error sending close on timeout: {}", e)));
+                self.deadline = Some(Instant::now() + TIMEOUT_CLOSE_WAIT);
+            }
+        }
+
+        let trace_level = self.options.shared.message_trace_level;
+        match self.socket.read() {
+            Ok(msg) => {
+                self.idle_tracker.reset();
+                match msg {
+                    Message::Text(message) => {
+                        shared::trace_on_text_message(&message, trace_level.text);
+                        handler.on_text_message(self, message.as_bytes());
+                    },
+                    Message::Binary(bytes) => {
+                        shared::trace_on_binary_message(&bytes, trace_level.binary);
+                        handler.on_binary_message(self, &bytes);
+                    },
+                    Message::Ping(bytes) => {
+                        shared::trace_on_ping_message(&bytes, trace_level.ping);
+                        handler.on_ping(self, &bytes);
+                    },
+                    Message::Pong(bytes) => {
+                        shared::trace_on_pong_message(&bytes, trace_level.pong);
+                        handler.on_pong(self, &bytes);
+                    },
+                    Message::Close(close_frame) => {
+                        shared::trace_on_close_frame(&close_frame);
+                        let quit_reason = shared::quit_reason_from_close_frame(&close_frame);
+                        let close_info = shared::close_info_from_close_frame(&close_frame, self.closed_by());
+                        handler.on_connection_closed(self, close_info);
+                        return PollResult::Break(self.quit(handler, quit_reason));
+                    },
+                    Message::Frame(_) => {
+                        shared::trace_on_frame(trace_level.frame);
                     }
-                },
-                Err(error) => {
-                    let (reason, should_break) = shared::handle_read_error(error);
-                    if let Some(error_msg) = reason {
-                        if should_break {
-                            if shared::is_connection_closed_error(&error_msg) {
-                                handler.on_connection_closed(self, Some(error_msg));
-                            } else {
-                                handler.on_error(self, error_msg);
-                            }
-                            handler.on_quit(self);
-                            break;
-                        }
-                    } else {
-                        handler.on_idle(self);
+                }
+                PollResult::Continue
+            },
+            Err(error) => {
+                let (reason, should_break) = shared::handle_read_error(error);
+                match reason {
+                    Some(error_msg) if should_break => {
+                        let quit_reason = if shared::is_connection_closed_error(&error_msg) {
+                            handler.on_connection_closed(self, shared::close_info_from_error(error_msg.clone()));
+                            QuitReason::GracefulClose { code: None, reason: Some(error_msg) }
+                        } else {
+                            handler.on_error(self, error_msg.clone());
+                            QuitReason::Error(error_msg)
+                        };
+                        PollResult::Break(self.quit(handler, quit_reason))
+                    },
+                    Some(_) => PollResult::Continue,
+                    None => {
+                        let idle_info = self.idle_tracker.record_idle();
+                        handler.on_idle(self, &idle_info);
+                        PollResult::Idle
                     }
                 }
-            };
-
-            // Optionally sleep to reduce CPU usage
-            if let Some(duration) = self.options.shared.spin_wait_duration {
-                thread::sleep(duration);
             }
         }
     }
 
+    /// Calls `on_quit` once and stores the reason, with [`QuitReason::Timeout`] after a timeout, later calls return the stored reason
+    #[inline]
+    fn quit<HANDLER>(&mut self, handler: &mut HANDLER, quit_reason: QuitReason) -> QuitReason
+    where
+        HANDLER: S9WebSocketClientHandler<Self> + ?Sized,
+    {
+        if let Some(quit_reason) = &self.quit_reason {
+            return quit_reason.clone();
+        }
+        let quit_reason = if self.timed_out { QuitReason::Timeout } else { quit_reason };
+        self.quit_reason = Some(quit_reason.clone());
+        handler.on_quit(self, quit_reason.clone());
+        quit_reason
+    }
+
     /// Sends a text message over the WebSocket connection.
     ///
     /// The message is immediately flushed to the socket.
+    /// Inside handler callbacks, wrap sends in [`send_or_handler_error!`](crate::send_or_handler_error)
+    /// to pass errors to [`S9WebSocketClientHandler::on_send_error`].
     #[inline]
     pub fn send_text_message(&mut self, text: &str) -> S9Result<()> {
         shared::send_text_message_to_websocket(&mut self.socket, text)
@@ -136,6 +356,7 @@ impl S9NonBlockingWebSocketClient {
     /// Sends a WebSocket ping frame.
     ///
     /// Can be used for keep-alive or latency measurement. The message is immediately flushed.
+    /// Returns [`S9WebSocketError::InvalidConfiguration`](crate::S9WebSocketError::InvalidConfiguration) if the payload exceeds 125 bytes.
     #[inline]
     pub fn send_ping(&mut self, data: Vec<u8>) -> S9Result<()> {
         shared::send_ping_to_websocket(&mut self.socket, data)
@@ -144,6 +365,7 @@ impl S9NonBlockingWebSocketClient {
     /// Sends a WebSocket pong frame.
     ///
     /// Typically used to respond to ping frames. The message is immediately flushed.
+    /// Returns [`S9WebSocketError::InvalidConfiguration`](crate::S9WebSocketError::InvalidConfiguration) if the payload exceeds 125 bytes.
     #[inline]
     pub fn send_pong(&mut self, data: Vec<u8>) -> S9Result<()> {
         shared::send_pong_to_websocket(&mut self.socket, data)
@@ -151,12 +373,24 @@ impl S9NonBlockingWebSocketClient {
 
     /// Initiates a graceful close of the WebSocket connection.
     ///
-    /// Sends a close frame to the server.
+    /// Sends a close frame with code `1000` (normal closure) to the server.
     /// The event loop continues until the server responds with a close frame or an error occurs.
     pub fn close(&mut self) {
+        self.close_initiated = true;
         shared::close_websocket_with_logging(&mut self.socket, "on close");
     }
 
+    /// Initiates a graceful close of the WebSocket connection with a custom close code and reason.
+    ///
+    /// Sends a close frame with the given code (e.g. `1000` for normal closure) to the server.
+    /// Code `0` sends a close frame without code and reason.
+    /// The event loop continues until the server responds with a close frame or an error occurs.
+    #[inline]
+    pub fn send_close(&mut self, code: u16, reason: &str) -> S9Result<()> {
+        self.close_initiated = true;
+        shared::send_close_to_websocket(&mut self.socket, code, reason)
+    }
+
     /// Immediately breaks the event loop without sending a close frame.
     ///
     /// Use this when you need to stop the client immediately, e.g. no close frame from server.
@@ -165,6 +399,116 @@ impl S9NonBlockingWebSocketClient {
         self.running = false;
     }
 
+    /// Splits the client into a [`NonBlockingSender`] and a [`NonBlockingReceiver`].
+    ///
+    /// Allows separate read and write paths on the current thread without [`run()`](Self::run)
+    /// and a handler. Both halves borrow the client, so it cannot be used otherwise until they are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use s9_websocket::{NonBlockingOptions, S9NonBlockingWebSocketClient};
+    /// use std::net::TcpListener;
+    /// use std::thread;
+    /// use tungstenite::Message;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let listener = TcpListener::bind("127.0.0.1:0")?;
+    /// # let addr = listener.local_addr()?;
+    /// # let server = thread::spawn(move || {
+    /// #     let (stream, _) = listener.accept().unwrap();
+    /// #     let mut socket = tungstenite::accept(stream).unwrap();
+    /// #     let message = socket.read().unwrap();
+    /// #     socket.send(message).unwrap();
+    /// #     while socket.read().is_ok() {}
+    /// # });
+    /// let mut client = S9NonBlockingWebSocketClient::connect(&format!("ws://{}", addr), NonBlockingOptions::new())?;
+    /// let (sender, receiver) = client.split();
+    ///
+    /// sender.send_text_message("Hello!")?;
+    /// loop {
+    ///     if let Some(Message::Text(text)) = receiver.poll_message()? {
+    ///         assert_eq!(text.as_str(), "Hello!");
+    ///         break;
+    ///     }
+    /// }
+    /// sender.close();
+    /// # drop((sender, receiver));
+    /// # drop(client);
+    /// # server.join().unwrap();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn split(&mut self) -> (NonBlockingSender<'_>, NonBlockingReceiver<'_>) {
+        nonblocking_split::split(self)
+    }
+
+    /// Returns the details of the established connection.
+    ///
+    /// Same as passed to [`S9WebSocketClientHandler::on_activated`].
+    #[inline]
+    pub fn connection_info(&self) -> &ConnectionInfo {
+        &self.connection_info
+    }
+
+    /// Returns the URI the client connected to.
+    ///
+    /// Useful for diagnostic display and as the target of a reconnect.
+    #[inline]
+    pub fn get_uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// Returns the custom HTTP headers sent during the WebSocket handshake.
+    #[inline]
+    pub fn get_headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    /// Returns how long the connection has been established.
+    ///
+    /// Measured from the completed WebSocket handshake in [`connect_with_headers()`](Self::connect_with_headers).
+    /// Useful for session expiry or auth token refresh in handler callbacks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use s9_websocket::{NonBlockingOptions, S9NonBlockingWebSocketClient};
+    /// use std::net::TcpListener;
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let listener = TcpListener::bind("127.0.0.1:0")?;
+    /// # let addr = listener.local_addr()?;
+    /// # let server = thread::spawn(move || {
+    /// #     let (stream, _) = listener.accept().unwrap();
+    /// #     let _socket = tungstenite::accept(stream).unwrap();
+    /// #     thread::sleep(Duration::from_millis(100));
+    /// # });
+    /// let client = S9NonBlockingWebSocketClient::connect(&format!("ws://{}", addr), NonBlockingOptions::new())?;
+    ///
+    /// let first = client.connection_age().unwrap();
+    /// thread::sleep(Duration::from_millis(10));
+    /// let second = client.connection_age().unwrap();
+    /// assert!(second > first);
+    /// # drop(client);
+    /// # server.join().unwrap();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn connection_age(&self) -> Option<Duration> {
+        self.connected_at.map(|connected_at| connected_at.elapsed())
+    }
+
+    /// Side that initiated the close, depending on whether the client sent a close frame first
+    #[inline]
+    fn closed_by(&self) -> ClosedBy {
+        if self.close_initiated { ClosedBy::Client } else { ClosedBy::Server }
+    }
+
     /// Returns a reference to the underlying WebSocket.
     ///
     /// This provides low-level access to the tungstenite WebSocket for advanced use cases.
@@ -182,6 +526,37 @@ impl S9NonBlockingWebSocketClient {
     pub fn get_socket_mut(&mut self) -> &mut WebSocket<MaybeTlsStream<TcpStream>> {
         &mut self.socket
     }
+
+    /// Returns the configured trace levels of received messages
+    #[inline]
+    pub(crate) fn message_trace_level(&self) -> MessageTraceLevel {
+        self.options.shared.message_trace_level
+    }
+}
+
+/// Access to the underlying WebSocket for utilities generic over both callback clients,
+/// also implemented by [`S9BlockingWebSocketClient`](crate::S9BlockingWebSocketClient).
+///
+/// Same as [`get_socket()`](Self::get_socket).
+///
+/// ```
+/// use std::net::TcpStream;
+/// use tungstenite::stream::MaybeTlsStream;
+/// use tungstenite::WebSocket;
+///
+/// fn can_write<T: AsRef<WebSocket<MaybeTlsStream<TcpStream>>>>(client: &T) -> bool {
+///     client.as_ref().can_write()
+/// }
+///
+/// fn both_writable(a: &s9_websocket::S9NonBlockingWebSocketClient, b: &s9_websocket::S9BlockingWebSocketClient) -> bool {
+///     can_write(a) && can_write(b)
+/// }
+/// ```
+impl AsRef<WebSocket<MaybeTlsStream<TcpStream>>> for S9NonBlockingWebSocketClient {
+    #[inline]
+    fn as_ref(&self) -> &WebSocket<MaybeTlsStream<TcpStream>> {
+        self.get_socket()
+    }
 }
 
 impl Drop for S9NonBlockingWebSocketClient {
@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+use std::io::ErrorKind;
+use std::rc::Rc;
+use tungstenite::Message;
+use crate::error::{S9Result, S9WebSocketError};
+use super::nonblocking_client::S9NonBlockingWebSocketClient;
+use super::shared;
+
+// ============================================================================
+// NonBlockingSender / NonBlockingReceiver - Split halves of the non-blocking client
+// ============================================================================
+
+type SharedClient<'a> = Rc<RefCell<&'a mut S9NonBlockingWebSocketClient>>;
+
+/// Write half of a [`S9NonBlockingWebSocketClient`], created by [`split()`](S9NonBlockingWebSocketClient::split).
+///
+/// Shares the client with its [`NonBlockingReceiver`] on the same thread. Neither half is `Send`,
+/// using them from multiple threads requires explicit synchronization around the client instead.
+pub struct NonBlockingSender<'a> {
+    client: SharedClient<'a>,
+}
+
+impl NonBlockingSender<'_> {
+    /// Sends a text message over the WebSocket connection.
+    ///
+    /// See [`S9NonBlockingWebSocketClient::send_text_message`].
+    #[inline]
+    pub fn send_text_message(&self, text: &str) -> S9Result<()> {
+        self.client.borrow_mut().send_text_message(text)
+    }
+
+    /// Sends a binary message over the WebSocket connection.
+    ///
+    /// See [`S9NonBlockingWebSocketClient::send_binary_message`].
+    #[inline]
+    pub fn send_binary_message(&self, data: Vec<u8>) -> S9Result<()> {
+        self.client.borrow_mut().send_binary_message(data)
+    }
+
+    /// Initiates a graceful close of the WebSocket connection.
+    ///
+    /// Keep polling the [`NonBlockingReceiver`] until the server's close frame is received.
+    #[inline]
+    pub fn close(&self) {
+        self.client.borrow_mut().close();
+    }
+
+    /// Marks the client as no longer running.
+    ///
+    /// See [`S9NonBlockingWebSocketClient::force_quit`].
+    #[inline]
+    pub fn force_quit(&self) {
+        self.client.borrow_mut().force_quit();
+    }
+}
+
+/// Read half of a [`S9NonBlockingWebSocketClient`], created by [`split()`](S9NonBlockingWebSocketClient::split).
+///
+/// Shares the client with its [`NonBlockingSender`] on the same thread. Neither half is `Send`,
+/// using them from multiple threads requires explicit synchronization around the client instead.
+pub struct NonBlockingReceiver<'a> {
+    client: SharedClient<'a>,
+}
+
+impl NonBlockingReceiver<'_> {
+    /// Reads the next message from the socket without blocking.
+    ///
+    /// Returns `Ok(None)` if no data is available. Read errors are returned as [`S9WebSocketError`],
+    /// after the close handshake completed as [`S9WebSocketError::Closed`].
+    #[inline]
+    pub fn poll_message(&self) -> S9Result<Option<Message>> {
+        let mut client = self.client.borrow_mut();
+        let trace_level = client.message_trace_level();
+        match client.get_socket_mut().read() {
+            Ok(message) => {
+                match &message {
+                    Message::Text(text) => shared::trace_on_text_message(text, trace_level.text),
+                    Message::Binary(bytes) => shared::trace_on_binary_message(bytes, trace_level.binary),
+                    Message::Ping(bytes) => shared::trace_on_ping_message(bytes, trace_level.ping),
+                    Message::Pong(bytes) => shared::trace_on_pong_message(bytes, trace_level.pong),
+                    Message::Close(close_frame) => shared::trace_on_close_frame(close_frame),
+                    Message::Frame(_) => shared::trace_on_frame(trace_level.frame),
+                }
+                Ok(Some(message))
+            },
+            Err(tungstenite::Error::Io(io_err)) if matches!(io_err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                // No data available (TimedOut e.g. on Windows)
+                Ok(None)
+            },
+            Err(error) => Err(S9WebSocketError::from(error)),
+        }
+    }
+}
+
+/// Splits the client into a sender and a receiver sharing it on the current thread
+pub(crate) fn split(client: &mut S9NonBlockingWebSocketClient) -> (NonBlockingSender<'_>, NonBlockingReceiver<'_>) {
+    let client = Rc::new(RefCell::new(client));
+    (NonBlockingSender { client: Rc::clone(&client) }, NonBlockingReceiver { client })
+}
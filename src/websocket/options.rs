@@ -1,21 +1,310 @@
+use std::fmt;
+use std::sync::Arc;
 use std::time::Duration;
+use crossbeam_channel::Sender;
+use tungstenite::protocol::WebSocketConfig;
 use crate::error::{S9Result, S9WebSocketError};
+use super::types::WebSocketEvent;
 
 // ============================================================================
 // Configuration options
 // ============================================================================
 
-#[derive(Debug, Clone, Default)]
+/// Minimum read and write timeout the OS can reliably honor
+const MIN_OS_TIMEOUT: Duration = Duration::from_micros(1);
+
+/// Read timeouts below this value effectively result in a busy loop
+const BUSY_LOOP_READ_TIMEOUT: Duration = Duration::from_micros(100);
+
+#[inline]
+fn validate_timeout(timeout: Option<Duration>, kind: &str) -> S9Result<()> {
+    match timeout {
+        Some(timeout) if timeout.is_zero() => Err(S9WebSocketError::InvalidConfiguration(format!("{} timeout duration cannot be zero", kind))),
+        _ => Ok(()),
+    }
+}
+
+#[inline]
+fn validate_read_timeout(timeout: Option<Duration>) -> S9Result<()> {
+    validate_timeout(timeout, "Read")?;
+    if let Some(timeout) = timeout {
+        if timeout < MIN_OS_TIMEOUT {
+            return Err(S9WebSocketError::InvalidConfiguration("read timeout below minimum 1µs".to_string()));
+        }
+        if timeout < BUSY_LOOP_READ_TIMEOUT && tracing::enabled!(tracing::Level::WARN) {
+            tracing::warn!("Read timeout of {:?} is below {:?} and effectively busy loops, consider using S9NonBlockingWebSocketClient instead", timeout, BUSY_LOOP_READ_TIMEOUT);
+        }
+    }
+    Ok(())
+}
+
+#[inline]
+fn validate_write_timeout(timeout: Option<Duration>) -> S9Result<()> {
+    validate_timeout(timeout, "Write")?;
+    if let Some(timeout) = timeout {
+        if timeout < MIN_OS_TIMEOUT && tracing::enabled!(tracing::Level::WARN) {
+            tracing::warn!("Write timeout of {:?} is below {:?} and cannot be honored reliably by the OS", timeout, MIN_OS_TIMEOUT);
+        }
+    }
+    Ok(())
+}
+
+#[inline]
+fn check_timeouts(read_timeout: Option<Duration>, write_timeout: Option<Duration>) {
+    if let (Some(read_timeout), Some(write_timeout)) = (read_timeout, write_timeout) {
+        if write_timeout < read_timeout && tracing::enabled!(tracing::Level::WARN) {
+            tracing::warn!("Write timeout of {:?} is below read timeout of {:?}, the values may be swapped", write_timeout, read_timeout);
+        }
+    }
+}
+
+#[inline]
+fn validate_spin_wait_duration(duration: Option<Duration>) -> S9Result<()> {
+    match duration {
+        Some(duration) if duration.is_zero() => Err(S9WebSocketError::InvalidConfiguration("Spin wait duration cannot be zero".to_string())),
+        _ => Ok(()),
+    }
+}
+
+#[inline]
+fn validate_capacity(capacity: Option<usize>, channel: &str) -> S9Result<()> {
+    match capacity {
+        Some(0) => Err(S9WebSocketError::InvalidConfiguration(format!("{} channel capacity cannot be zero", channel))),
+        _ => Ok(()),
+    }
+}
+
+#[inline]
+fn validate_heartbeat_interval(interval: Option<Duration>) -> S9Result<()> {
+    match interval {
+        Some(interval) if interval.is_zero() => Err(S9WebSocketError::InvalidConfiguration("Heartbeat interval cannot be zero".to_string())),
+        _ => Ok(()),
+    }
+}
+
+#[inline]
+fn validate_stats_interval(interval: Option<Duration>) -> S9Result<()> {
+    match interval {
+        Some(interval) if interval.is_zero() => Err(S9WebSocketError::InvalidConfiguration("Stats interval cannot be zero".to_string())),
+        _ => Ok(()),
+    }
+}
+
+#[inline]
+fn validate_write_buffer_sizes(write_buffer_size: Option<usize>, max_write_buffer_size: Option<usize>) -> S9Result<()> {
+    let defaults = WebSocketConfig::default();
+    let write_buffer_size = write_buffer_size.unwrap_or(defaults.write_buffer_size);
+    let max_write_buffer_size = max_write_buffer_size.unwrap_or(defaults.max_write_buffer_size);
+    if max_write_buffer_size <= write_buffer_size {
+        return Err(S9WebSocketError::InvalidConfiguration(format!("Max write buffer size ({}) must be greater than write buffer size ({})", max_write_buffer_size, write_buffer_size)));
+    }
+    Ok(())
+}
+
+/// Tracing levels for logging received messages per message type.
+///
+/// All levels default to `TRACE`. Raising e.g. the level for text messages to `DEBUG` logs them
+/// in production without enabling `TRACE` for the whole crate in the tracing subscriber.
+///
+/// # Examples
+///
+/// ```
+/// use s9_websocket::{MessageTraceLevel, NonBlockingOptions};
+/// use tracing::Level;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = NonBlockingOptions::builder()
+///     .message_trace_level(MessageTraceLevel {
+///         text: Level::DEBUG,
+///         ..MessageTraceLevel::default()
+///     })
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageTraceLevel {
+    /// Level for received text messages
+    pub text: tracing::Level,
+    /// Level for received binary messages
+    pub binary: tracing::Level,
+    /// Level for received ping frames
+    pub ping: tracing::Level,
+    /// Level for received pong frames
+    pub pong: tracing::Level,
+    /// Level for received raw frames
+    pub frame: tracing::Level,
+}
+
+impl Default for MessageTraceLevel {
+    fn default() -> Self {
+        Self {
+            text: tracing::Level::TRACE,
+            binary: tracing::Level::TRACE,
+            ping: tracing::Level::TRACE,
+            pong: tracing::Level::TRACE,
+            frame: tracing::Level::TRACE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct SharedOptions {
     pub(crate) spin_wait_duration: Option<Duration>,
     pub(crate) nodelay: Option<bool>,
     pub(crate) ttl: Option<u32>,
+    pub(crate) write_buffer_size: Option<usize>,
+    pub(crate) max_write_buffer_size: Option<usize>,
+    pub(crate) message_trace_level: MessageTraceLevel,
+}
+
+impl SharedOptions {
+    /// Sets the tracing levels for received messages
+    pub(crate) fn message_trace_level(mut self, level: MessageTraceLevel) -> Self {
+        self.message_trace_level = level;
+        self
+    }
+
+    /// Creates the tungstenite config, unset values keep the tungstenite defaults
+    pub(crate) fn websocket_config(&self) -> S9Result<WebSocketConfig> {
+        validate_write_buffer_sizes(self.write_buffer_size, self.max_write_buffer_size)?;
+        let mut config = WebSocketConfig::default();
+        if let Some(write_buffer_size) = self.write_buffer_size {
+            config = config.write_buffer_size(write_buffer_size);
+        }
+        if let Some(max_write_buffer_size) = self.max_write_buffer_size {
+            config = config.max_write_buffer_size(max_write_buffer_size);
+        }
+        Ok(config)
+    }
+
+    /// Collects the human-readable `name: value` pairs of all set options
+    fn display_fields(&self) -> Vec<String> {
+        let mut fields = Vec::new();
+        if let Some(spin_wait_duration) = self.spin_wait_duration {
+            fields.push(format!("spin_wait: {:?}", spin_wait_duration));
+        }
+        if let Some(nodelay) = self.nodelay {
+            fields.push(format!("nodelay: {}", nodelay));
+        }
+        if let Some(ttl) = self.ttl {
+            fields.push(format!("ttl: {}", ttl));
+        }
+        if let Some(write_buffer_size) = self.write_buffer_size {
+            fields.push(format!("write_buffer_size: {}", write_buffer_size));
+        }
+        if let Some(max_write_buffer_size) = self.max_write_buffer_size {
+            fields.push(format!("max_write_buffer_size: {}", max_write_buffer_size));
+        }
+        if self.message_trace_level != MessageTraceLevel::default() {
+            let level = &self.message_trace_level;
+            fields.push(format!("message_trace_level: text={} binary={} ping={} pong={} frame={}", level.text, level.binary, level.ping, level.pong, level.frame));
+        }
+        fields
+    }
+}
+
+/// Common access to the options of all clients, used when connecting
+pub(crate) trait ClientOptions: fmt::Display {
+    fn shared(&self) -> &SharedOptions;
+}
+
+impl Default for SharedOptions {
+    fn default() -> Self {
+        Self {
+            spin_wait_duration: None,
+            // Low latency by default, disable Nagle's algorithm coalescing small messages
+            nodelay: Some(true),
+            ttl: None,
+            write_buffer_size: None,
+            max_write_buffer_size: None,
+            message_trace_level: MessageTraceLevel::default(),
+        }
+    }
+}
+
+/// Predicate deciding which events the async client publishes to its event channel
+#[derive(Clone)]
+pub(crate) struct EventFilter(pub(crate) Arc<dyn Fn(&WebSocketEvent) -> bool + Send + Sync>);
+
+impl fmt::Debug for EventFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EventFilter(..)")
+    }
 }
 
 /// Configuration options for the non-blocking WebSocket client.
+///
+/// Options are comparable with `==`. The [`event_filter`](Self::event_filter) and
+/// [`error_sink`](Self::error_sink) are not comparable and therefore ignored.
+///
+/// ```
+/// use s9_websocket::NonBlockingOptions;
+///
+/// assert_eq!(NonBlockingOptions::new(), NonBlockingOptions::new().event_filter(|_| true));
+/// assert_ne!(NonBlockingOptions::new(), NonBlockingOptions::new().nodelay(false));
+/// ```
 #[derive(Debug, Clone, Default)]
 pub struct NonBlockingOptions {
     pub(crate) shared: SharedOptions,
+    pub(crate) busy_spin_count: u32,
+    pub(crate) event_capacity: Option<usize>,
+    pub(crate) control_capacity: Option<usize>,
+    pub(crate) thread_name: Option<String>,
+    pub(crate) event_filter: Option<EventFilter>,
+    pub(crate) heartbeat_interval: Option<Duration>,
+    pub(crate) stats_interval: Option<Duration>,
+    pub(crate) error_sink: Option<Sender<String>>,
+}
+
+impl PartialEq for NonBlockingOptions {
+    fn eq(&self, other: &Self) -> bool {
+        // Closures and channels are not comparable
+        self.shared == other.shared
+            && self.busy_spin_count == other.busy_spin_count
+            && self.event_capacity == other.event_capacity
+            && self.control_capacity == other.control_capacity
+            && self.thread_name == other.thread_name
+            && self.heartbeat_interval == other.heartbeat_interval
+            && self.stats_interval == other.stats_interval
+    }
+}
+
+impl fmt::Display for NonBlockingOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fields = self.shared.display_fields();
+        if self.busy_spin_count > 0 {
+            fields.push(format!("busy_spin_count: {}", self.busy_spin_count));
+        }
+        if let Some(event_capacity) = self.event_capacity {
+            fields.push(format!("event_capacity: {}", event_capacity));
+        }
+        if let Some(control_capacity) = self.control_capacity {
+            fields.push(format!("control_capacity: {}", control_capacity));
+        }
+        if let Some(thread_name) = &self.thread_name {
+            fields.push(format!("thread_name: {}", thread_name));
+        }
+        if self.event_filter.is_some() {
+            fields.push("event_filter: set".to_string());
+        }
+        if let Some(heartbeat_interval) = self.heartbeat_interval {
+            fields.push(format!("heartbeat_interval: {:?}", heartbeat_interval));
+        }
+        if let Some(stats_interval) = self.stats_interval {
+            fields.push(format!("stats_interval: {:?}", stats_interval));
+        }
+        if self.error_sink.is_some() {
+            fields.push("error_sink: set".to_string());
+        }
+        write!(f, "NonBlockingOptions {{ {} }}", fields.join(", "))
+    }
+}
+
+impl ClientOptions for NonBlockingOptions {
+    fn shared(&self) -> &SharedOptions {
+        &self.shared
+    }
 }
 
 impl NonBlockingOptions {
@@ -26,6 +315,50 @@ impl NonBlockingOptions {
         Self::default()
     }
 
+    /// Creates an [`OptionsBuilder`] collecting all options and validating them in [`build()`](OptionsBuilder::build).
+    ///
+    /// Prefer this over the fallible methods on `NonBlockingOptions`, as it avoids `?` in the middle of method chains.
+    pub fn builder() -> OptionsBuilder {
+        OptionsBuilder::new()
+    }
+
+    /// Returns the human-readable configuration, same as the [`Display`](fmt::Display) output.
+    ///
+    /// Only options which are set are included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use s9_websocket::NonBlockingOptions;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let options = NonBlockingOptions::builder()
+    ///     .spin_wait_duration(Some(Duration::from_millis(10)))
+    ///     .ttl(Some(64))
+    ///     .build()?;
+    ///
+    /// assert_eq!(options.to_config_string(), "NonBlockingOptions { spin_wait: 10ms, nodelay: true, ttl: 64 }");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_config_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Validates all options.
+    ///
+    /// Returns [`S9WebSocketError::InvalidConfiguration`] for the first invalid value.
+    pub fn validate(&self) -> S9Result<()> {
+        validate_spin_wait_duration(self.shared.spin_wait_duration)?;
+        validate_capacity(self.event_capacity, "Event")?;
+        validate_capacity(self.control_capacity, "Control")?;
+        validate_heartbeat_interval(self.heartbeat_interval)?;
+        validate_stats_interval(self.stats_interval)?;
+        validate_write_buffer_sizes(self.shared.write_buffer_size, self.shared.max_write_buffer_size)?;
+        Ok(())
+    }
+
     /// Sets the sleep duration between event loop iterations.
     ///
     /// - `None`: Maximum performance, 100% CPU usage (busy spin loop)
@@ -33,16 +366,40 @@ impl NonBlockingOptions {
     ///
     /// Duration must be greater than zero if specified.
     pub fn spin_wait_duration(mut self, duration: Option<Duration>) -> S9Result<Self> {
-        if let Some(duration) = duration {
-            if duration.is_zero() {
-                return Err(S9WebSocketError::InvalidConfiguration("Spin wait duration cannot be zero".to_string()).into());
-            }
-        }
+        validate_spin_wait_duration(duration)?;
         self.shared.spin_wait_duration = duration;
         Ok(self)
     }
 
+    /// Sets the number of consecutive idle iterations (reads without data) before sleeping
+    /// for [`spin_wait_duration`](Self::spin_wait_duration).
+    ///
+    /// The event loop busy spins until `count` reads in a row returned no data and sleeps afterwards,
+    /// which keeps the latency low for bursts of messages without constant CPU usage. Any received message
+    /// resets the counter, iterations of the async client while reads are paused count as idle. Defaults to `0`, sleeping on every iteration. Has no effect without `spin_wait_duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use s9_websocket::NonBlockingOptions;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let options = NonBlockingOptions::builder()
+    ///     .spin_wait_duration(Some(Duration::from_millis(1)))
+    ///     .busy_spin_count(1000)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn busy_spin_count(mut self, count: u32) -> Self {
+        self.busy_spin_count = count;
+        self
+    }
+
     /// Enables or disables the `TCP_NODELAY` option for messages to be sent.
+    ///
+    /// Defaults to `true` for low latency. Set to `false` to let Nagle's algorithm coalesce small messages.
     pub fn nodelay(mut self, nodelay: bool) -> Self {
         self.shared.nodelay = Some(nodelay);
         self
@@ -54,16 +411,359 @@ impl NonBlockingOptions {
         self.shared.ttl = ttl;
         Ok(self)
     }
+
+    /// Sets the target size of the write buffer in bytes.
+    ///
+    /// Messages are buffered until the buffer reaches this size and then written to the socket.
+    /// `0` writes every message to the socket immediately (unbuffered). Defaults to the
+    /// tungstenite default of 128 KiB if not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use s9_websocket::NonBlockingOptions;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // Unbuffered writes
+    /// let options = NonBlockingOptions::builder()
+    ///     .write_buffer_size(0)
+    ///     .build()?;
+    ///
+    /// // The max write buffer size must be greater than the write buffer size
+    /// let result = NonBlockingOptions::builder()
+    ///     .write_buffer_size(4096)
+    ///     .max_write_buffer_size(4096)
+    ///     .build();
+    /// assert!(result.is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_buffer_size(mut self, size: usize) -> Self {
+        self.shared.write_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the maximum size of the write buffer in bytes.
+    ///
+    /// Writing fails with [`S9WebSocketError::Tungstenite`] if the buffer would exceed this size,
+    /// e.g. when the socket cannot keep up with outgoing messages. Must be greater than the
+    /// write buffer size. Unlimited if not set.
+    pub fn max_write_buffer_size(mut self, size: usize) -> Self {
+        self.shared.max_write_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the tracing levels for logging received messages.
+    ///
+    /// See [`MessageTraceLevel`].
+    pub fn message_trace_level(mut self, level: MessageTraceLevel) -> Self {
+        self.shared = self.shared.message_trace_level(level);
+        self
+    }
+
+    /// Sets the capacity of the event channel of the async client.
+    ///
+    /// When the channel is full, the event loop thread blocks until the receiver drains events.
+    /// Unbounded if not set. Capacity must be greater than zero.
+    ///
+    /// Only used by [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient).
+    pub fn event_capacity(mut self, capacity: usize) -> S9Result<Self> {
+        validate_capacity(Some(capacity), "Event")?;
+        self.event_capacity = Some(capacity);
+        Ok(self)
+    }
+
+    /// Sets the capacity of the control channel of the async client.
+    ///
+    /// When the channel is full, the blocking send methods such as `send_text()` wait, the `try_*` methods
+    /// such as `try_send_text()` return [`S9WebSocketError::SendQueueFull`].
+    /// Unbounded if not set. Capacity must be greater than zero.
+    ///
+    /// Only used by [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient).
+    pub fn control_capacity(mut self, capacity: usize) -> S9Result<Self> {
+        validate_capacity(Some(capacity), "Control")?;
+        self.control_capacity = Some(capacity);
+        Ok(self)
+    }
+
+    /// Sets the name of the event loop thread spawned by the async client.
+    ///
+    /// Defaults to `ws-<host>` derived from the connection URI, e.g. `ws-echo.websocket.org`.
+    /// Only used by [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient).
+    pub fn thread_name(mut self, name: impl Into<String>) -> Self {
+        self.thread_name = Some(name.into());
+        self
+    }
+
+    /// Sets a filter deciding which events are published to the event channel of the async client.
+    ///
+    /// Events for which the filter returns `false` are dropped. [`WebSocketEvent::Quit`] is passed to the filter
+    /// as well, but always published.
+    ///
+    /// Only used by [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient).
+    pub fn event_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&WebSocketEvent) -> bool + Send + Sync + 'static,
+    {
+        self.event_filter = Some(EventFilter(Arc::new(filter)));
+        self
+    }
+
+    /// Sets the interval for publishing [`WebSocketEvent::Heartbeat`] events.
+    ///
+    /// The event loop thread publishes a heartbeat at this interval regardless of data activity.
+    /// Nothing is sent to the server. Disabled if `None`. Duration must be greater than zero if specified.
+    ///
+    /// Only used by [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient).
+    pub fn heartbeat_interval(mut self, interval: Option<Duration>) -> S9Result<Self> {
+        validate_heartbeat_interval(interval)?;
+        self.heartbeat_interval = interval;
+        Ok(self)
+    }
+
+    /// Sets the interval for publishing [`WebSocketEvent::Stats`] events.
+    ///
+    /// The event loop thread publishes a [`ConnectionStats`](crate::ConnectionStats) snapshot at this interval.
+    /// Disabled if `None`. Duration must be greater than zero if specified.
+    ///
+    /// Only used by [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient).
+    pub fn stats_interval(mut self, interval: Option<Duration>) -> S9Result<Self> {
+        validate_stats_interval(interval)?;
+        self.stats_interval = interval;
+        Ok(self)
+    }
+
+    /// Sets a secondary channel receiving the payload of every [`WebSocketEvent::Error`].
+    ///
+    /// Useful for error logging components not interested in all other events. Errors are forwarded
+    /// regardless of the [`event_filter`](Self::event_filter).
+    ///
+    /// The error sink never blocks the event loop: errors are dropped (and logged at WARN) if a bounded
+    /// sink is full because its receiver is not draining, or if the receiver is gone.
+    ///
+    /// Only used by [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use s9_websocket::S9AsyncClientBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (error_tx, error_rx) = crossbeam_channel::bounded::<String>(64);
+    ///
+    /// let mut client = S9AsyncClientBuilder::new()
+    ///     .error_sink(error_tx)
+    ///     .connect("wss://echo.websocket.org")?;
+    /// client.run()?;
+    ///
+    /// std::thread::spawn(move || {
+    ///     for error in error_rx {
+    ///         eprintln!("WebSocket error: {}", error);
+    ///     }
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn error_sink(mut self, error_tx: Sender<String>) -> Self {
+        self.error_sink = Some(error_tx);
+        self
+    }
+}
+
+/// Builder for [`NonBlockingOptions`] validating all options at once in [`build()`](Self::build).
+///
+/// All setters are infallible, so the method chain is never interrupted by `?`.
+///
+/// # Examples
+///
+/// ```
+/// use s9_websocket::NonBlockingOptions;
+/// use std::time::Duration;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = NonBlockingOptions::builder()
+///     .spin_wait_duration(Some(Duration::from_millis(10)))
+///     .nodelay(true)
+///     .build()?;
+///
+/// // Invalid values are reported by build()
+/// let result = NonBlockingOptions::builder()
+///     .spin_wait_duration(Some(Duration::ZERO))
+///     .build();
+/// assert!(result.is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OptionsBuilder {
+    options: NonBlockingOptions,
+}
+
+impl OptionsBuilder {
+    /// Creates a new builder with default options.
+    pub fn new() -> Self {
+        Self {
+            options: NonBlockingOptions::default(),
+        }
+    }
+
+    /// Sets the sleep duration between event loop iterations.
+    ///
+    /// See [`NonBlockingOptions::spin_wait_duration`].
+    pub fn spin_wait_duration(mut self, duration: Option<Duration>) -> Self {
+        self.options.shared.spin_wait_duration = duration;
+        self
+    }
+
+    /// Sets the number of consecutive idle iterations spinning before sleeping.
+    ///
+    /// See [`NonBlockingOptions::busy_spin_count`].
+    pub fn busy_spin_count(mut self, count: u32) -> Self {
+        self.options.busy_spin_count = count;
+        self
+    }
+
+    /// Enables or disables the `TCP_NODELAY` option for messages to be sent.
+    ///
+    /// See [`NonBlockingOptions::nodelay`].
+    pub fn nodelay(mut self, nodelay: bool) -> Self {
+        self.options.shared.nodelay = Some(nodelay);
+        self
+    }
+
+    /// Sets the TTL (Time To Live, # of hops) for the socket.
+    ///
+    /// See [`NonBlockingOptions::ttl`].
+    pub fn ttl(mut self, ttl: Option<u32>) -> Self {
+        self.options.shared.ttl = ttl;
+        self
+    }
+
+    /// Sets the target size of the write buffer in bytes.
+    ///
+    /// See [`NonBlockingOptions::write_buffer_size`].
+    pub fn write_buffer_size(mut self, size: usize) -> Self {
+        self.options.shared.write_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the maximum size of the write buffer in bytes.
+    ///
+    /// See [`NonBlockingOptions::max_write_buffer_size`].
+    pub fn max_write_buffer_size(mut self, size: usize) -> Self {
+        self.options.shared.max_write_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the tracing levels for logging received messages.
+    ///
+    /// See [`MessageTraceLevel`].
+    pub fn message_trace_level(mut self, level: MessageTraceLevel) -> Self {
+        self.options.shared = self.options.shared.message_trace_level(level);
+        self
+    }
+
+    /// Sets the capacity of the event channel of the async client.
+    ///
+    /// See [`NonBlockingOptions::event_capacity`].
+    pub fn event_capacity(mut self, capacity: usize) -> Self {
+        self.options.event_capacity = Some(capacity);
+        self
+    }
+
+    /// Sets the capacity of the control channel of the async client.
+    ///
+    /// See [`NonBlockingOptions::control_capacity`].
+    pub fn control_capacity(mut self, capacity: usize) -> Self {
+        self.options.control_capacity = Some(capacity);
+        self
+    }
+
+    /// Sets the name of the event loop thread spawned by the async client.
+    ///
+    /// See [`NonBlockingOptions::thread_name`].
+    pub fn thread_name(mut self, name: impl Into<String>) -> Self {
+        self.options.thread_name = Some(name.into());
+        self
+    }
+
+    /// Sets a filter deciding which events are published to the event channel of the async client.
+    ///
+    /// See [`NonBlockingOptions::event_filter`].
+    pub fn event_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&WebSocketEvent) -> bool + Send + Sync + 'static,
+    {
+        self.options.event_filter = Some(EventFilter(Arc::new(filter)));
+        self
+    }
+
+    /// Sets the interval for publishing heartbeat events of the async client.
+    ///
+    /// See [`NonBlockingOptions::heartbeat_interval`].
+    pub fn heartbeat_interval(mut self, interval: Option<Duration>) -> Self {
+        self.options.heartbeat_interval = interval;
+        self
+    }
+
+    /// Sets the interval for publishing statistics events of the async client.
+    ///
+    /// See [`NonBlockingOptions::stats_interval`].
+    pub fn stats_interval(mut self, interval: Option<Duration>) -> Self {
+        self.options.stats_interval = interval;
+        self
+    }
+
+    /// Sets a secondary channel receiving the payload of every error event of the async client.
+    ///
+    /// See [`NonBlockingOptions::error_sink`].
+    pub fn error_sink(mut self, error_tx: Sender<String>) -> Self {
+        self.options.error_sink = Some(error_tx);
+        self
+    }
+
+    /// Validates all collected options and returns the resulting [`NonBlockingOptions`].
+    ///
+    /// Returns [`S9WebSocketError::InvalidConfiguration`] for the first invalid value.
+    pub fn build(self) -> S9Result<NonBlockingOptions> {
+        self.options.validate()?;
+        Ok(self.options)
+    }
+}
+
+impl Default for OptionsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Configuration options for the blocking WebSocket client.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct BlockingOptions {
     pub(crate) shared: SharedOptions,
     pub(crate) read_timeout: Option<Duration>,
     pub(crate) write_timeout: Option<Duration>,
 }
 
+impl fmt::Display for BlockingOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fields = self.shared.display_fields();
+        if let Some(read_timeout) = self.read_timeout {
+            fields.push(format!("read_timeout: {:?}", read_timeout));
+        }
+        if let Some(write_timeout) = self.write_timeout {
+            fields.push(format!("write_timeout: {:?}", write_timeout));
+        }
+        write!(f, "BlockingOptions {{ {} }}", fields.join(", "))
+    }
+}
+
+impl ClientOptions for BlockingOptions {
+    fn shared(&self) -> &SharedOptions {
+        &self.shared
+    }
+}
+
 impl BlockingOptions {
     /// Creates a new `BlockingOptions` with default values.
     ///
@@ -72,6 +772,28 @@ impl BlockingOptions {
         Self::default()
     }
 
+    /// Returns the human-readable configuration, same as the [`Display`](fmt::Display) output.
+    ///
+    /// Only options which are set are included.
+    pub fn to_config_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Validates all options.
+    ///
+    /// Returns [`S9WebSocketError::InvalidConfiguration`] for the first invalid value. A write timeout
+    /// below the read timeout is unusual and logged as warning, as the values may be swapped.
+    ///
+    /// Called by [`S9BlockingWebSocketClient::connect`](crate::S9BlockingWebSocketClient::connect) before connecting.
+    pub fn validate(&self) -> S9Result<()> {
+        validate_spin_wait_duration(self.shared.spin_wait_duration)?;
+        validate_read_timeout(self.read_timeout)?;
+        validate_write_timeout(self.write_timeout)?;
+        validate_write_buffer_sizes(self.shared.write_buffer_size, self.shared.max_write_buffer_size)?;
+        check_timeouts(self.read_timeout, self.write_timeout);
+        Ok(())
+    }
+
     /// Sets the sleep duration between event loop iterations.
     ///
     /// - `None`: No sleep (only meaningful with read/write timeouts)
@@ -79,16 +801,14 @@ impl BlockingOptions {
     ///
     /// Duration must be greater than zero if specified.
     pub fn spin_wait_duration(mut self, duration: Option<Duration>) -> S9Result<Self> {
-        if let Some(duration) = duration {
-            if duration.is_zero() {
-                return Err(S9WebSocketError::InvalidConfiguration("Spin wait duration cannot be zero".to_string()).into());
-            }
-        }
+        validate_spin_wait_duration(duration)?;
         self.shared.spin_wait_duration = duration;
         Ok(self)
     }
 
     /// Enables or disables the `TCP_NODELAY` option for messages to be sent.
+    ///
+    /// Defaults to `true` for low latency. Set to `false` to let Nagle's algorithm coalesce small messages.
     pub fn nodelay(mut self, nodelay: bool) -> Self {
         self.shared.nodelay = Some(nodelay);
         self
@@ -101,26 +821,51 @@ impl BlockingOptions {
         Ok(self)
     }
 
+    /// Sets the target size of the write buffer in bytes.
+    ///
+    /// Messages are buffered until the buffer reaches this size and then written to the socket.
+    /// `0` writes every message to the socket immediately (unbuffered). Defaults to the
+    /// tungstenite default of 128 KiB if not set.
+    pub fn write_buffer_size(mut self, size: usize) -> Self {
+        self.shared.write_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the maximum size of the write buffer in bytes.
+    ///
+    /// Writing fails with [`S9WebSocketError::Tungstenite`] if the buffer would exceed this size,
+    /// e.g. when the socket cannot keep up with outgoing messages. Must be greater than the
+    /// write buffer size. Unlimited if not set.
+    pub fn max_write_buffer_size(mut self, size: usize) -> Self {
+        self.shared.max_write_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the tracing levels for logging received messages.
+    ///
+    /// See [`MessageTraceLevel`].
+    pub fn message_trace_level(mut self, level: MessageTraceLevel) -> Self {
+        self.shared = self.shared.message_trace_level(level);
+        self
+    }
+
     /// Sets the read timeout for the socket.
-    /// Must be None for the indefinitely blocking of socket read or greater than zero
+    /// Must be None for the indefinitely blocking of socket read or at least 1µs.
+    ///
+    /// The OS cannot reliably honor sub-microsecond timeouts, so these are rejected. Timeouts below
+    /// 100µs are accepted but effectively busy loop, consider [`S9NonBlockingWebSocketClient`](crate::S9NonBlockingWebSocketClient) instead.
     pub fn read_timeout(mut self, timeout: Option<Duration>) -> S9Result<Self> {
-        if let Some(timeout) = timeout {
-            if timeout.is_zero() {
-                return Err(S9WebSocketError::InvalidConfiguration("Read timeout duration cannot be zero".to_string()).into());
-            }
-        }
+        validate_read_timeout(timeout)?;
         self.read_timeout = timeout;
         Ok(self)
     }
 
     /// Sets the write timeout for the socket.
-    /// /// Must be None for the indefinitely blocking of socket write or greater than zero
+    /// Must be None for the indefinitely blocking of socket write or greater than zero.
+    ///
+    /// Sub-microsecond timeouts are accepted with a warning, as the OS cannot reliably honor them.
     pub fn write_timeout(mut self, timeout: Option<Duration>) -> S9Result<Self> {
-        if let Some(timeout) = timeout {
-            if timeout.is_zero() {
-                return Err(S9WebSocketError::InvalidConfiguration("Write timeout duration cannot be zero".to_string()).into());
-            }
-        }
+        validate_write_timeout(timeout)?;
         self.write_timeout = timeout;
         Ok(self)
     }
@@ -1,44 +1,251 @@
 use std::collections::HashMap;
 use std::net::TcpStream;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 use tungstenite::stream::MaybeTlsStream;
 use tungstenite::{Bytes, ClientRequestBuilder, Error, Message, Utf8Bytes, WebSocket};
+use tungstenite::handshake::HandshakeError;
 use tungstenite::handshake::client::Response;
 use tungstenite::http::Uri;
 use tungstenite::protocol::CloseFrame;
+use tungstenite::protocol::frame::coding::CloseCode;
 use crate::error::{S9Result, S9WebSocketError};
-use super::options::{NonBlockingOptions, BlockingOptions};
-use super::types::ControlMessage;
+use super::options::{NonBlockingOptions, BlockingOptions, ClientOptions};
+use super::types::{CloseInfo, ClosedBy, ConnectionInfo, IdleInfo, ConnectionStats, ControlMessage, QuitReason};
 
 // ============================================================================
 // Shared Internal Helpers
 // ============================================================================
 
-/// Control flow indicator for message handling loops
-pub(crate) enum ControlFlow {
+/// Emits an event at a level only known at runtime, as tracing requires constant levels at the callsite
+macro_rules! event_at_level {
+    ($level:expr, $($arg:tt)+) => {
+        match $level {
+            tracing::Level::ERROR => tracing::error!($($arg)+),
+            tracing::Level::WARN => tracing::warn!($($arg)+),
+            tracing::Level::INFO => tracing::info!($($arg)+),
+            tracing::Level::DEBUG => tracing::debug!($($arg)+),
+            _ => tracing::trace!($($arg)+),
+        }
+    };
+}
+
+/// Maximum payload size of control frames (RFC 6455, section 5.5)
+const MAX_CONTROL_FRAME_PAYLOAD: usize = 125;
+
+/// Result of handling a control message in message handling loops
+pub(crate) enum ControlResult {
     Continue,
     Break,
+    PauseReads,
+    ResumeReads,
+    /// Writing to the socket failed
+    SendError(S9WebSocketError),
+    /// The control message itself is invalid, e.g. an oversized ping payload
+    ConfigError(S9WebSocketError),
+    /// A close frame was sent on [`ControlMessage::Close`]
+    Closing,
+}
+
+/// Tracks consecutive reads without data for [`S9WebSocketClientHandler::on_idle`](super::types::S9WebSocketClientHandler::on_idle)
+#[derive(Debug, Default)]
+pub(crate) struct IdleTracker {
+    current: Option<IdleInfo>,
+}
+
+impl IdleTracker {
+    /// Records a read without data, starting a new idle period if none is ongoing
+    #[inline]
+    pub(crate) fn record_idle(&mut self) -> IdleInfo {
+        let info = self.current.get_or_insert_with(|| IdleInfo { since: Instant::now(), consecutive_idle_count: 0 });
+        info.consecutive_idle_count += 1;
+        *info
+    }
+
+    /// Ends the current idle period on a received message
+    #[inline]
+    pub(crate) fn reset(&mut self) {
+        self.current = None;
+    }
 }
 
-/// Establishes WebSocket connection with optional custom headers
-pub(crate) fn connect_socket(uri: &str, headers: &HashMap<String, String>) -> S9Result<(WebSocket<MaybeTlsStream<TcpStream>>, Response)> {
-    let uri = Uri::from_str(uri).map_err(|e| {
+/// Message counters updated by the event loop thread
+#[derive(Debug, Default)]
+pub(crate) struct StatsCounters {
+    connect_duration: Option<Duration>,
+    messages_received: AtomicU64,
+    bytes_received: AtomicU64,
+    messages_sent: AtomicU64,
+    bytes_sent: AtomicU64,
+}
+
+impl StatsCounters {
+    pub(crate) fn new(connect_duration: Duration) -> Self {
+        Self {
+            connect_duration: Some(connect_duration),
+            ..Self::default()
+        }
+    }
+
+    #[inline]
+    pub(crate) fn record_received(&self, len: usize) {
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        self.bytes_received.fetch_add(len as u64, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn record_sent(&self, len: usize) {
+        self.messages_sent.fetch_add(1, Ordering::Relaxed);
+        self.bytes_sent.fetch_add(len as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> ConnectionStats {
+        ConnectionStats {
+            last_connect_duration: self.connect_duration,
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            messages_sent: self.messages_sent.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Established connection with the durations of the connection phases
+pub(crate) struct ConnectResult {
+    pub(crate) socket: WebSocket<MaybeTlsStream<TcpStream>>,
+    pub(crate) response: Response,
+    /// Duration of the TCP connect, including DNS resolution
+    pub(crate) tcp_elapsed: Duration,
+    /// Duration of the TLS negotiation (for `wss://`) and the HTTP upgrade
+    pub(crate) handshake_elapsed: Duration,
+}
+
+impl ConnectResult {
+    /// Total duration of all connection phases
+    #[inline]
+    pub(crate) fn connect_duration(&self) -> Duration {
+        self.tcp_elapsed + self.handshake_elapsed
+    }
+}
+
+/// Collects the custom headers passed to `connect_with_headers()`
+pub(crate) fn collect_headers(headers: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>) -> HashMap<String, String> {
+    headers.into_iter()
+        .map(|(key, value)| (key.into(), value.into()))
+        .collect()
+}
+
+/// Maximum number of HTTP redirects followed while connecting, same as tungstenite's `connect()`
+const MAX_REDIRECTS: u8 = 3;
+
+/// Establishes WebSocket connection with optional custom headers and the configured write buffer sizes
+///
+/// Connects the TCP stream separately to measure the connection phases. tungstenite performs the
+/// TLS negotiation and the HTTP upgrade in one call, so these are measured together. Up to
+/// [`MAX_REDIRECTS`] HTTP redirects are followed, the phases are summed up over all attempts.
+pub(crate) fn connect_socket<O: ClientOptions>(uri: &str, headers: &HashMap<String, String>, options: &O) -> S9Result<ConnectResult> {
+    if tracing::enabled!(tracing::Level::DEBUG) {
+        tracing::debug!("Connecting with options: {}", options);
+    }
+    let config = options.shared().websocket_config()?;
+
+    let mut uri = Uri::from_str(uri).map_err(|e| {
         tracing::error!("S9WebSocketClient error connecting to invalid URI: {}", uri);
         S9WebSocketError::InvalidUri(e.to_string())
     })?;
 
-    let mut builder = ClientRequestBuilder::new(uri);
-    for (key, value) in headers {
-        builder = builder.with_header(key, value);
+    let mut tcp_elapsed = Duration::ZERO;
+    let mut handshake_elapsed = Duration::ZERO;
+    let mut redirects = 0;
+    loop {
+        let (host, port) = host_and_port(&uri)?;
+
+        let mut builder = ClientRequestBuilder::new(uri.clone());
+        for (key, value) in headers {
+            builder = builder.with_header(key, value);
+        }
+
+        let started_at = Instant::now();
+        let stream = TcpStream::connect((host.as_str(), port)).map_err(|e| {
+            tracing::error!("Error connecting to {}:{}: {}", host, port, e);
+            S9WebSocketError::from(e)
+        })?;
+        // Set before the handshake like tungstenite's connect(), also when not configured
+        stream.set_nodelay(options.shared().nodelay.unwrap_or(true))?;
+        tcp_elapsed += started_at.elapsed();
+
+        let handshake_started_at = Instant::now();
+        let result = tungstenite::client_tls_with_config(builder, stream, Some(config), None);
+        handshake_elapsed += handshake_started_at.elapsed();
+
+        match result {
+            Ok((socket, response)) => {
+                trace_on_connected(&response);
+                if tracing::enabled!(tracing::Level::DEBUG) {
+                    tracing::debug!("Connected in {:?} (TCP: {:?}, handshake: {:?})", tcp_elapsed + handshake_elapsed, tcp_elapsed, handshake_elapsed);
+                }
+                return Ok(ConnectResult { socket, response, tcp_elapsed, handshake_elapsed });
+            },
+            Err(HandshakeError::Failure(Error::Http(response))) if response.status().is_redirection() && redirects < MAX_REDIRECTS => {
+                let Some(location) = response.headers().get("Location").and_then(|value| value.to_str().ok()) else {
+                    return Err(S9WebSocketError::from(Error::Http(response)));
+                };
+                let location = Uri::from_str(location)
+                    .map_err(|e| S9WebSocketError::InvalidUri(format!("Invalid redirect location {}: {}", location, e)))?;
+                if tracing::enabled!(tracing::Level::DEBUG) {
+                    tracing::debug!("Redirected from {} to {}", uri, location);
+                }
+                uri = location;
+                redirects += 1;
+            },
+            Err(HandshakeError::Failure(error)) => return Err(S9WebSocketError::from(error)),
+            Err(HandshakeError::Interrupted(_)) => {
+                // Only happens on non-blocking streams, the stream is still blocking during the handshake
+                return Err(S9WebSocketError::Io(std::io::Error::new(
+                    std::io::ErrorKind::Interrupted,
+                    "WebSocket handshake interrupted before completion",
+                )));
+            },
+        }
     }
+}
 
-    let (sock, response) = tungstenite::connect(builder)?;
-    trace_on_connected(&response);
+/// Extracts the host to connect to and the port, defaulting to the port of the `ws` or `wss` scheme
+fn host_and_port(uri: &Uri) -> S9Result<(String, u16)> {
+    let default_port = match uri.scheme_str() {
+        Some("ws") => 80,
+        Some("wss") => 443,
+        _ => return Err(S9WebSocketError::InvalidUri(format!("Unsupported URI scheme, expected ws or wss: {}", uri))),
+    };
+    let host = uri.host()
+        .ok_or_else(|| S9WebSocketError::InvalidUri(format!("URI without host: {}", uri)))?
+        // IPv6 addresses are enclosed in brackets in URIs
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+    Ok((host, uri.port_u16().unwrap_or(default_port)))
+}
 
-    Ok((sock, response))
+/// Derives the event loop thread name `ws-<host>` from the URI, if it contains a host
+pub(crate) fn default_thread_name(uri: &str) -> Option<String> {
+    Uri::from_str(uri).ok()
+        .and_then(|uri| uri.host().map(|host| format!("ws-{}", host)))
 }
 
-/// Configures socket for non-blocking operation with TCP_NODELAY
+/// Builds the connection details from the handshake response
+pub(crate) fn connection_info(uri: &str, response: &Response) -> ConnectionInfo {
+    ConnectionInfo {
+        uri: uri.to_string(),
+        status_code: response.status().as_u16(),
+        negotiated_subprotocol: response.headers()
+            .get("Sec-WebSocket-Protocol")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string()),
+    }
+}
+
+/// Configures socket for non-blocking operation, TCP_NODELAY is already set by `connect_socket()`
 pub(crate) fn configure_non_blocking(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, options: &NonBlockingOptions) -> S9Result<()> {
     let stream = match socket.get_mut() {
         MaybeTlsStream::Plain(stream) => stream,
@@ -48,9 +255,6 @@ pub(crate) fn configure_non_blocking(socket: &mut WebSocket<MaybeTlsStream<TcpSt
 
     stream.set_nonblocking(true)?;
 
-    if let Some(nodelay) = options.shared.nodelay {
-        stream.set_nodelay(nodelay)?;
-    }
     if let Some(ttl) = options.shared.ttl {
         stream.set_ttl(ttl)?;
     }
@@ -58,7 +262,7 @@ pub(crate) fn configure_non_blocking(socket: &mut WebSocket<MaybeTlsStream<TcpSt
     Ok(())
 }
 
-/// Configures socket for blocking operation
+/// Configures socket for blocking operation, TCP_NODELAY is already set by `connect_socket()`
 pub(crate) fn configure_blocking(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, options: &BlockingOptions) -> S9Result<()> {
     let stream = match socket.get_mut() {
         MaybeTlsStream::Plain(stream) => stream,
@@ -66,9 +270,6 @@ pub(crate) fn configure_blocking(socket: &mut WebSocket<MaybeTlsStream<TcpStream
         _ => return Ok(()),
     };
 
-    if let Some(nodelay) = options.shared.nodelay {
-        stream.set_nodelay(nodelay)?;
-    }
     if let Some(ttl) = options.shared.ttl {
         stream.set_ttl(ttl)?;
     }
@@ -80,45 +281,76 @@ pub(crate) fn configure_blocking(socket: &mut WebSocket<MaybeTlsStream<TcpStream
 
 /// Handles control messages for non-blocking clients
 #[inline]
-pub(crate) fn handle_control_message(control_msg: ControlMessage, socket: &mut WebSocket<MaybeTlsStream<TcpStream>>) -> Result<ControlFlow, String> {
+pub(crate) fn handle_control_message(control_msg: ControlMessage, socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, stats: &StatsCounters) -> ControlResult {
     match control_msg {
         ControlMessage::SendText(text) => {
             if let Err(e) = send_text_message_to_websocket(socket, &text) {
-                return Err(format!("Error sending text: {}", e));
+                return ControlResult::SendError(e);
+            }
+            stats.record_sent(text.len());
+            ControlResult::Continue
+        },
+        ControlMessage::SendTextArc(text) => {
+            if let Err(e) = send_text_message_to_websocket(socket, &text) {
+                return ControlResult::SendError(e);
             }
-            Ok(ControlFlow::Continue)
+            stats.record_sent(text.len());
+            ControlResult::Continue
         },
         ControlMessage::SendBinary(data) => {
+            let len = data.len();
             if let Err(e) = send_binary_message_to_websocket(socket, data) {
-                return Err(format!("Error sending binary: {}", e));
+                return ControlResult::SendError(e);
             }
-            Ok(ControlFlow::Continue)
+            stats.record_sent(len);
+            ControlResult::Continue
         },
         ControlMessage::SendPing(data) => {
+            if let Err(e) = validate_control_frame_payload(&data, "Ping") {
+                return ControlResult::ConfigError(e);
+            }
             if let Err(e) = send_ping_to_websocket(socket, data) {
-                return Err(format!("Error sending ping: {}", e));
+                return ControlResult::SendError(e);
             }
-            Ok(ControlFlow::Continue)
+            ControlResult::Continue
         },
         ControlMessage::SendPong(data) => {
+            if let Err(e) = validate_control_frame_payload(&data, "Pong") {
+                return ControlResult::ConfigError(e);
+            }
             if let Err(e) = send_pong_to_websocket(socket, data) {
-                return Err(format!("Error sending pong: {}", e));
+                return ControlResult::SendError(e);
             }
-            Ok(ControlFlow::Continue)
+            ControlResult::Continue
         },
         ControlMessage::Close() => {
             close_websocket_with_logging(socket, "ControlMessage::Close");
-            Ok(ControlFlow::Continue)
+            ControlResult::Closing
         },
         ControlMessage::ForceQuit() => {
             if tracing::enabled!(tracing::Level::TRACE) {
                 tracing::trace!("Forcibly quitting message loop");
             }
-            Ok(ControlFlow::Break)
+            ControlResult::Break
+        },
+        ControlMessage::PauseReads() => {
+            ControlResult::PauseReads
+        },
+        ControlMessage::ResumeReads() => {
+            ControlResult::ResumeReads
         }
     }
 }
 
+/// Validates the payload of a ping or pong frame, which must not exceed 125 bytes
+#[inline]
+fn validate_control_frame_payload(data: &[u8], frame: &str) -> S9Result<()> {
+    if data.len() > MAX_CONTROL_FRAME_PAYLOAD {
+        return Err(S9WebSocketError::InvalidConfiguration(format!("{} payload of {} bytes exceeds maximum of {} bytes", frame, data.len(), MAX_CONTROL_FRAME_PAYLOAD)));
+    }
+    Ok(())
+}
+
 /// Handles socket read errors consistently across clients
 pub(crate) fn handle_read_error(error: Error) -> (Option<String>, bool) {
     match error {
@@ -178,6 +410,7 @@ pub(crate) fn send_binary_message_to_websocket(socket: &mut WebSocket<MaybeTlsSt
 /// Sends ping to WebSocket
 #[inline]
 pub(crate) fn send_ping_to_websocket(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, data: Vec<u8>) -> S9Result<()> {
+    validate_control_frame_payload(&data, "Ping")?;
     socket.send(Message::Ping(data.into()))
         .map(|_| {
             if tracing::enabled!(tracing::Level::TRACE) {
@@ -193,6 +426,7 @@ pub(crate) fn send_ping_to_websocket(socket: &mut WebSocket<MaybeTlsStream<TcpSt
 /// Sends pong to WebSocket
 #[inline]
 pub(crate) fn send_pong_to_websocket(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, data: Vec<u8>) -> S9Result<()> {
+    validate_control_frame_payload(&data, "Pong")?;
     socket.send(Message::Pong(data.into()))
         .map(|_| {
             if tracing::enabled!(tracing::Level::TRACE) {
@@ -205,6 +439,26 @@ pub(crate) fn send_pong_to_websocket(socket: &mut WebSocket<MaybeTlsStream<TcpSt
         })
 }
 
+/// Sends close frame with code and reason to WebSocket
+#[inline]
+pub(crate) fn send_close_to_websocket(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, code: u16, reason: &str) -> S9Result<()> {
+    // Code 0 is not a valid close code and sends a close frame without code instead
+    let close_frame = (code != 0).then(|| CloseFrame {
+        code: CloseCode::from(code),
+        reason: Utf8Bytes::from(reason.to_string()),
+    });
+    socket.close(close_frame)
+        .map(|_| {
+            if tracing::enabled!(tracing::Level::TRACE) {
+                tracing::trace!("Sent close frame with code {} and reason: {}", code, reason);
+            }
+        })
+        .map_err(|e| {
+            tracing::error!("Error sending close frame: {}", e);
+            S9WebSocketError::from(e)
+        })
+}
+
 /// Determines if an error message indicates a connection closure
 #[inline]
 pub(crate) fn is_connection_closed_error(error_msg: &str) -> bool {
@@ -212,10 +466,14 @@ pub(crate) fn is_connection_closed_error(error_msg: &str) -> bool {
     error_msg.contains("Connection closed") || error_msg.contains("closed")
 }
 
-/// Closes WebSocket connection with context logging
+/// Closes WebSocket connection with normal closure code 1000 and context logging
 pub(crate) fn close_websocket_with_logging(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, context: &str) {
     if socket.can_write() {
-        socket.close(None)
+        let close_frame = CloseFrame {
+            code: CloseCode::Normal,
+            reason: Utf8Bytes::default(),
+        };
+        socket.close(Some(close_frame))
             .map(|_| {
                 tracing::trace!("Connection close successfully requested for context: {}", context);
             })
@@ -225,6 +483,34 @@ pub(crate) fn close_websocket_with_logging(socket: &mut WebSocket<MaybeTlsStream
     }
 }
 
+/// Builds the quit reason for a received close frame
+pub(crate) fn quit_reason_from_close_frame(close_frame: &Option<CloseFrame>) -> QuitReason {
+    match close_frame {
+        Some(cf) => QuitReason::GracefulClose {
+            code: Some(u16::from(cf.code)),
+            reason: if cf.reason.is_empty() { None } else { Some(cf.reason.to_string()) },
+        },
+        None => QuitReason::GracefulClose { code: None, reason: None },
+    }
+}
+
+/// Builds close info from a received close frame
+pub(crate) fn close_info_from_close_frame(close_frame: &Option<CloseFrame>, initiated_by: ClosedBy) -> CloseInfo {
+    match close_frame {
+        Some(cf) => CloseInfo {
+            code: Some(u16::from(cf.code)),
+            reason: if cf.reason.is_empty() { None } else { Some(cf.reason.to_string()) },
+            initiated_by,
+        },
+        None => CloseInfo { code: None, reason: None, initiated_by },
+    }
+}
+
+/// Builds close info for a connection that ended without a received close frame
+pub(crate) fn close_info_from_error(error_msg: String) -> CloseInfo {
+    CloseInfo { code: None, reason: Some(error_msg), initiated_by: ClosedBy::Error }
+}
+
 /// Traces connection establishment
 pub(crate) fn trace_on_connected(response: &Response) {
     if tracing::enabled!(tracing::Level::TRACE) {
@@ -237,36 +523,28 @@ pub(crate) fn trace_on_connected(response: &Response) {
     }
 }
 
-/// Traces text message receipt
+/// Traces text message receipt at the configured level
 #[inline]
-pub(crate) fn trace_on_text_message(message: &Utf8Bytes) {
-    if tracing::enabled!(tracing::Level::TRACE) {
-        tracing::trace!("Received text message: {}", message);
-    }
+pub(crate) fn trace_on_text_message(message: &Utf8Bytes, level: tracing::Level) {
+    event_at_level!(level, "Received text message: {}", message);
 }
 
-/// Traces binary message receipt
+/// Traces binary message receipt at the configured level
 #[inline]
-pub(crate) fn trace_on_binary_message(bytes: &Bytes) {
-    if tracing::enabled!(tracing::Level::TRACE) {
-        tracing::trace!("Received binary message: {:?}", bytes);
-    }
+pub(crate) fn trace_on_binary_message(bytes: &Bytes, level: tracing::Level) {
+    event_at_level!(level, "Received binary message: {} bytes", bytes.len());
 }
 
-/// Traces ping message receipt
+/// Traces ping message receipt at the configured level
 #[inline]
-pub(crate) fn trace_on_ping_message(bytes: &Bytes) {
-    if tracing::enabled!(tracing::Level::TRACE) {
-        tracing::trace!("Received ping frame: {}", String::from_utf8_lossy(&bytes));
-    }
+pub(crate) fn trace_on_ping_message(bytes: &Bytes, level: tracing::Level) {
+    event_at_level!(level, "Received ping frame: {} bytes", bytes.len());
 }
 
-/// Traces pong message receipt
+/// Traces pong message receipt at the configured level
 #[inline]
-pub(crate) fn trace_on_pong_message(bytes: &Bytes) {
-    if tracing::enabled!(tracing::Level::TRACE) {
-        tracing::trace!("Received pong frame: {}", String::from_utf8_lossy(&bytes));
-    }
+pub(crate) fn trace_on_pong_message(bytes: &Bytes, level: tracing::Level) {
+    event_at_level!(level, "Received pong frame: {} bytes", bytes.len());
 }
 
 /// Traces connection close frame receipt
@@ -283,10 +561,8 @@ pub(crate) fn trace_on_close_frame(close_frame: &Option<CloseFrame>) {
     }
 }
 
-/// Traces frame message receipt
+/// Traces frame message receipt at the configured level
 #[inline]
-pub(crate) fn trace_on_frame() {
-    if tracing::enabled!(tracing::Level::TRACE) {
-        tracing::trace!("Received frame from server");
-    }
+pub(crate) fn trace_on_frame(level: tracing::Level) {
+    event_at_level!(level, "Received frame from server");
 }
@@ -2,9 +2,17 @@
 //!
 //! This module provides the public API types used for WebSocket communication:
 //! - [`S9WebSocketClientHandler`] - Trait for handler-based event callbacks
+//! - [`ConnectionInfo`] - Details of the established connection
+//! - [`QuitReason`] - Reason why an event loop terminated
+//! - [`ConnectionStats`] - Message statistics of the async non-blocking client
 //! - [`WebSocketEvent`] - Events received from async non-blocking client
 //! - [`ControlMessage`] - Control messages sent to async non-blocking client
 
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use crate::error::S9WebSocketError;
+
 // ============================================================================
 // Macros
 // ============================================================================
@@ -12,7 +20,11 @@
 // Send message to channel or break when sending a message fails.
 macro_rules! send_or_break {
     ($sender:expr, $context:expr, $event:expr) => {
-        if let Err(e) = $sender.send($event) {
+        let event = $event;
+        if tracing::enabled!(tracing::Level::TRACE) {
+            tracing::trace!(payload_len = event.payload_len(), "Sending context {} through channel: {}", $context, event);
+        }
+        if let Err(e) = $sender.send(event) {
             tracing::error!("Failed to send context {} through channel: {}", $context, e);
             break;
         }
@@ -22,7 +34,11 @@ macro_rules! send_or_break {
 // Send message to channel or log when sending a message fails.
 macro_rules! send_or_log {
     ($sender:expr, $context:expr, $event:expr) => {
-        if let Err(e) = $sender.send($event) {
+        let event = $event;
+        if tracing::enabled!(tracing::Level::TRACE) {
+            tracing::trace!(payload_len = event.payload_len(), "Sending context {} through channel: {}", $context, event);
+        }
+        if let Err(e) = $sender.send(event) {
             tracing::error!("Failed to send context {} through channel: {}", $context, e);
         }
     };
@@ -31,6 +47,39 @@ macro_rules! send_or_log {
 pub(crate) use send_or_break;
 pub(crate) use send_or_log;
 
+/// Evaluates a send on a callback client and passes an error to the handler's
+/// [`on_send_error`](crate::S9WebSocketClientHandler::on_send_error) instead of returning it.
+///
+/// Usable inside handler callbacks of [`S9NonBlockingWebSocketClient`](crate::S9NonBlockingWebSocketClient)
+/// and [`S9BlockingWebSocketClient`](crate::S9BlockingWebSocketClient), where `$send` evaluates to `S9Result<()>`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use s9_websocket::{send_or_handler_error, S9NonBlockingWebSocketClient, S9WebSocketClientHandler, S9WebSocketError};
+///
+/// struct EchoHandler;
+///
+/// impl S9WebSocketClientHandler<S9NonBlockingWebSocketClient> for EchoHandler {
+///     fn on_text_message(&mut self, client: &mut S9NonBlockingWebSocketClient, data: &[u8]) {
+///         let text = String::from_utf8_lossy(data);
+///         send_or_handler_error!(client, self, client.send_text_message(&text));
+///     }
+///
+///     fn on_send_error(&mut self, _client: &mut S9NonBlockingWebSocketClient, error: S9WebSocketError) {
+///         eprintln!("Echo failed: {}", error);
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! send_or_handler_error {
+    ($client:expr, $handler:expr, $send:expr) => {
+        if let Err(e) = $send {
+            $crate::S9WebSocketClientHandler::on_send_error($handler, $client, e);
+        }
+    };
+}
+
 // ============================================================================
 // Public API Types
 // ============================================================================
@@ -66,6 +115,7 @@ pub(crate) use send_or_log;
 /// - [`on_pong`](Self::on_pong) - Handle pong frames
 /// - [`on_connection_closed`](Self::on_connection_closed) - Handle connection closure
 /// - [`on_error`](Self::on_error) - Handle errors
+/// - [`on_send_error`](Self::on_send_error) - Handle send errors from [`send_or_handler_error!`](crate::send_or_handler_error)
 /// - [`on_quit`](Self::on_quit) - Cleanup before exit
 ///
 /// # Examples
@@ -73,7 +123,7 @@ pub(crate) use send_or_log;
 /// ## Basic Handler
 ///
 /// ```no_run
-/// use s9_websocket::{S9NonBlockingWebSocketClient, S9WebSocketClientHandler, NonBlockingOptions};
+/// use s9_websocket::{S9NonBlockingWebSocketClient, S9WebSocketClientHandler, NonBlockingOptions, CloseInfo};
 ///
 /// struct MyHandler {
 ///     message_count: usize,
@@ -93,8 +143,8 @@ pub(crate) use send_or_log;
 ///         println!("Received {} bytes", data.len());
 ///     }
 ///
-///     fn on_connection_closed(&mut self, _client: &mut S9NonBlockingWebSocketClient, reason: Option<String>) {
-///         println!("Connection closed: {:?}", reason);
+///     fn on_connection_closed(&mut self, _client: &mut S9NonBlockingWebSocketClient, info: CloseInfo) {
+///         println!("Connection closed by {:?}: {:?}", info.initiated_by, info.reason);
 ///     }
 ///
 ///     fn on_error(&mut self, _client: &mut S9NonBlockingWebSocketClient, error: String) {
@@ -113,7 +163,7 @@ pub(crate) use send_or_log;
 /// ## Using Lifecycle Hooks
 ///
 /// ```no_run
-/// use s9_websocket::{S9NonBlockingWebSocketClient, S9WebSocketClientHandler, NonBlockingOptions};
+/// use s9_websocket::{S9NonBlockingWebSocketClient, S9WebSocketClientHandler, NonBlockingOptions, ConnectionInfo, CloseInfo, IdleInfo, QuitReason};
 /// use crossbeam_channel::{unbounded, Receiver};
 ///
 /// enum Signal { Close, ForceQuit }
@@ -123,11 +173,11 @@ pub(crate) use send_or_log;
 /// }
 ///
 /// impl S9WebSocketClientHandler<S9NonBlockingWebSocketClient> for HandlerWithSignals {
-///     fn on_activated(&mut self, _client: &mut S9NonBlockingWebSocketClient) {
-///         println!("Handler activated - ready to receive messages");
+///     fn on_activated(&mut self, _client: &mut S9NonBlockingWebSocketClient, info: &ConnectionInfo) {
+///         println!("Handler activated for {} - ready to receive messages", info.uri);
 ///     }
 ///
-///     fn on_idle(&mut self, client: &mut S9NonBlockingWebSocketClient) {
+///     fn on_idle(&mut self, client: &mut S9NonBlockingWebSocketClient, _info: &IdleInfo) {
 ///         // Check for external signals when no WebSocket data available
 ///         if let Ok(signal) = self.signal_rx.try_recv() {
 ///             match signal {
@@ -142,13 +192,46 @@ pub(crate) use send_or_log;
 ///     }
 ///
 ///     fn on_binary_message(&mut self, _client: &mut S9NonBlockingWebSocketClient, _data: &[u8]) {}
-///     fn on_connection_closed(&mut self, _client: &mut S9NonBlockingWebSocketClient, _reason: Option<String>) {}
+///     fn on_connection_closed(&mut self, _client: &mut S9NonBlockingWebSocketClient, _info: CloseInfo) {}
 ///     fn on_error(&mut self, _client: &mut S9NonBlockingWebSocketClient, _error: String) {}
 ///
-///     fn on_quit(&mut self, _client: &mut S9NonBlockingWebSocketClient) {
-///         println!("Handler shutting down");
+///     fn on_quit(&mut self, _client: &mut S9NonBlockingWebSocketClient, reason: QuitReason) {
+///         println!("Handler shutting down: {:?}", reason);
+///     }
+/// }
+/// ```
+///
+/// ## Dynamic Handlers
+///
+/// The trait is object safe, all methods take `&mut self` and concrete parameters. Handlers can be
+/// selected at runtime as trait objects, both clients accept unsized handlers in `run()`.
+///
+/// ```no_run
+/// use s9_websocket::{S9NonBlockingWebSocketClient, S9WebSocketClientHandler, NonBlockingOptions};
+///
+/// struct Printer;
+/// impl S9WebSocketClientHandler<S9NonBlockingWebSocketClient> for Printer {
+///     fn on_text_message(&mut self, _client: &mut S9NonBlockingWebSocketClient, data: &[u8]) {
+///         println!("Received: {}", String::from_utf8_lossy(data));
+///     }
+/// }
+///
+/// struct Counter(usize);
+/// impl S9WebSocketClientHandler<S9NonBlockingWebSocketClient> for Counter {
+///     fn on_text_message(&mut self, _client: &mut S9NonBlockingWebSocketClient, _data: &[u8]) {
+///         self.0 += 1;
 ///     }
 /// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let verbose = std::env::args().any(|arg| arg == "--verbose");
+/// let mut handler: Box<dyn S9WebSocketClientHandler<S9NonBlockingWebSocketClient>> =
+///     if verbose { Box::new(Printer) } else { Box::new(Counter(0)) };
+///
+/// let mut client = S9NonBlockingWebSocketClient::connect("wss://echo.websocket.org", NonBlockingOptions::new())?;
+/// client.run(handler.as_mut());
+/// # Ok(())
+/// # }
 /// ```
 pub trait S9WebSocketClientHandler<C> {
     /// Called once before entering the event loop.
@@ -157,8 +240,12 @@ pub trait S9WebSocketClientHandler<C> {
     /// but before processing messages.
     ///
     /// **Default**: No-op (does nothing)
-    fn on_activated(&mut self, client: &mut C) {
-        let _ = client;
+    ///
+    /// # Parameters
+    /// - `client`: Mutable reference to the client, allowing direct method calls
+    /// - `info`: Details of the established connection, same as [`WebSocketEvent::Activated`]
+    fn on_activated(&mut self, client: &mut C, info: &ConnectionInfo) {
+        let _ = (client, info);
     }
 
     /// Called every event loop iteration before attempting to read from the socket.
@@ -180,14 +267,22 @@ pub trait S9WebSocketClientHandler<C> {
     ///
     /// This is suitable for lower-priority tasks that should only run when the connection is idle.
     ///
+    /// [`S9BlockingWebSocketClient`](crate::S9BlockingWebSocketClient) without read timeout treats WouldBlock/TimedOut
+    /// as error, but calls this first. The error is only raised if the handler does not call `force_quit()`.
+    ///
     /// **Default**: No-op (does nothing)
     ///
     /// # Use Cases
     /// - Checking external signals/channels
     /// - Background maintenance tasks
     /// - Graceful shutdown coordination
-    fn on_idle(&mut self, client: &mut C) {
-        let _ = client;
+    /// - Detecting prolonged idle periods, e.g. to send a ping
+    ///
+    /// # Parameters
+    /// - `client`: Mutable reference to the client
+    /// - `info`: Start and length of the current idle period
+    fn on_idle(&mut self, client: &mut C, info: &IdleInfo) {
+        let _ = (client, info);
     }
 
     /// Called when a text message is received.
@@ -215,7 +310,8 @@ pub trait S9WebSocketClientHandler<C> {
     ///
     /// # Note
     /// The `data` slice is borrowed from the underlying WebSocket message and is only
-    /// valid for the duration of this callback (zero-copy delivery).
+    /// valid for the duration of this callback (zero-copy delivery). tungstenite reassembles
+    /// fragmented messages before handing them out, so the whole payload is buffered in memory.
     fn on_binary_message(&mut self, client: &mut C, data: &[u8]) {
         let _ = (client, data);
     }
@@ -225,8 +321,9 @@ pub trait S9WebSocketClientHandler<C> {
     /// **Default**: No-op (does nothing)
     ///
     /// # Note
-    /// Pong responses are handled automatically by the underlying tungstenite library.
-    /// This callback is for monitoring/logging purposes only.
+    /// Pong responses are always sent automatically by the underlying tungstenite library,
+    /// which queues the reply before the ping is handed to the client. This cannot be disabled,
+    /// so this callback is for monitoring/logging purposes only.
     ///
     /// # Parameters
     /// - `client`: Mutable reference to the client
@@ -259,9 +356,9 @@ pub trait S9WebSocketClientHandler<C> {
     ///
     /// # Parameters
     /// - `client`: Mutable reference to the client
-    /// - `reason`: Optional close reason string from the server
-    fn on_connection_closed(&mut self, client: &mut C, reason: Option<String>) {
-        let _ = (client, reason);
+    /// - `info`: Close code, reason and the side that initiated the close
+    fn on_connection_closed(&mut self, client: &mut C, info: CloseInfo) {
+        let _ = (client, info);
     }
 
     /// Called when an error occurs during WebSocket operations.
@@ -276,9 +373,25 @@ pub trait S9WebSocketClientHandler<C> {
     fn on_error(&mut self, client: &mut C, error: String) {
         let _ = (client, error);
     }
+
+    /// Called when sending a message fails inside a callback using [`send_or_handler_error!`](crate::send_or_handler_error).
+    ///
+    /// Also called by [`S9NonBlockingWebSocketClient`](crate::S9NonBlockingWebSocketClient) if sending the close frame
+    /// fails once the timeout of [`run_until_quit()`](crate::S9NonBlockingWebSocketClient::run_until_quit) elapsed.
+    /// Unlike [`on_error`](Self::on_error), the event loop keeps running.
+    ///
+    /// **Default**: No-op (does nothing)
+    ///
+    /// # Parameters
+    /// - `client`: Mutable reference to the client
+    /// - `error`: The error returned by the send method
+    fn on_send_error(&mut self, client: &mut C, error: S9WebSocketError) {
+        let _ = (client, error);
+    }
     
     /// Called once when the event loop is about to terminate.
     ///
+    /// Guaranteed to be called exactly once before `run()` returns, however the event loop exits.
     /// This is called after:
     /// - [`on_connection_closed`](Self::on_connection_closed) (for graceful closes)
     /// - [`on_error`](Self::on_error) (for errors)
@@ -287,11 +400,140 @@ pub trait S9WebSocketClientHandler<C> {
     /// Use this for cleanup tasks.
     ///
     /// **Default**: No-op (does nothing)
-    fn on_quit(&mut self, client: &mut C) {
-        let _ = client;
+    ///
+    /// # Parameters
+    /// - `client`: Mutable reference to the client
+    /// - `reason`: Why the event loop is terminating
+    fn on_quit(&mut self, client: &mut C, reason: QuitReason) {
+        let _ = (client, reason);
     }
 }
 
+/// Details of an established WebSocket connection.
+///
+/// Passed to [`S9WebSocketClientHandler::on_activated`] and carried by [`WebSocketEvent::Activated`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionInfo {
+    /// URI the client connected to
+    pub uri: String,
+    /// HTTP status code of the handshake response, usually `101`
+    pub status_code: u16,
+    /// Subprotocol selected by the server via the `Sec-WebSocket-Protocol` header, if any
+    pub negotiated_subprotocol: Option<String>,
+}
+
+/// Details of the current idle period, passed to [`S9WebSocketClientHandler::on_idle`].
+///
+/// An idle period starts with the first read without data and ends with the next received message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleInfo {
+    /// When the current idle period started
+    pub since: Instant,
+    /// Number of consecutive reads without data, starting at `1`
+    pub consecutive_idle_count: u64,
+}
+
+/// Details of a closed WebSocket connection.
+///
+/// Passed to [`S9WebSocketClientHandler::on_connection_closed`] and carried by [`WebSocketEvent::ConnectionClosed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloseInfo {
+    /// Close code from the received Close frame, if any
+    pub code: Option<u16>,
+    /// Close reason from the received Close frame, or a description of the error
+    pub reason: Option<String>,
+    /// Which side initiated the close
+    pub initiated_by: ClosedBy,
+}
+
+/// Side that initiated the close of a WebSocket connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClosedBy {
+    /// The server sent a Close frame first
+    Server,
+    /// The client sent a Close frame first, e.g. via `close()` or [`ControlMessage::Close`]
+    Client,
+    /// The connection ended without a Close frame being received
+    Error,
+}
+
+/// Reason why an event loop terminated.
+///
+/// Passed to [`S9WebSocketClientHandler::on_quit`] and carried by [`WebSocketEvent::Quit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuitReason {
+    /// The connection was closed gracefully.
+    ///
+    /// Contains the close code and reason from the server's Close frame, if any.
+    GracefulClose {
+        code: Option<u16>,
+        reason: Option<String>,
+    },
+
+    /// An error occurred while reading from the WebSocket.
+    ///
+    /// Contains a description of the error.
+    Error(String),
+
+    /// The event loop was broken by `force_quit()` or [`ControlMessage::ForceQuit`].
+    ForceQuit,
+
+    /// All reconnect attempts have been exhausted.
+    ReconnectExhausted,
+
+    /// The deadline of [`S9NonBlockingWebSocketClient::run_until_quit`](crate::S9NonBlockingWebSocketClient::run_until_quit) has passed.
+    Timeout,
+}
+
+/// Result of a single event loop iteration via
+/// [`S9NonBlockingWebSocketClient::poll_once`](crate::S9NonBlockingWebSocketClient::poll_once) or
+/// [`S9BlockingWebSocketClient::poll_once`](crate::S9BlockingWebSocketClient::poll_once).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PollResult {
+    /// A message or a recoverable error was handled, poll again.
+    Continue,
+
+    /// The event loop terminated, `on_quit` was called with the contained [`QuitReason`].
+    Break(QuitReason),
+
+    /// No data was available on the non-blocking socket or within the read timeout.
+    Idle,
+}
+
+/// Snapshot of the message statistics of [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient).
+///
+/// Counts text and binary messages only, ping, pong and close frames are not included.
+/// Sent messages are counted once written to the socket successfully.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConnectionStats {
+    /// Duration of establishing the connection, from TCP connect to the completed WebSocket handshake
+    pub last_connect_duration: Option<Duration>,
+    /// Number of text and binary messages received
+    pub messages_received: u64,
+    /// Number of payload bytes of all received messages
+    pub bytes_received: u64,
+    /// Number of text and binary messages sent
+    pub messages_sent: u64,
+    /// Number of payload bytes of all sent messages
+    pub bytes_sent: u64,
+}
+
+/// Snapshot of the channel fill levels of [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient).
+///
+/// A length close to the capacity indicates a slow consumer, e.g. react with [`ControlMessage::PauseReads`]
+/// or configure a larger capacity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChannelStats {
+    /// Number of events queued in the event channel, `None` if not supported by the [`ChannelBackend`](crate::ChannelBackend)
+    pub event_channel_len: Option<usize>,
+    /// Number of control messages queued in the control channel
+    pub control_channel_len: usize,
+    /// Capacity of the event channel, `None` if unbounded
+    pub event_channel_capacity: Option<usize>,
+    /// Capacity of the control channel, `None` if unbounded
+    pub control_channel_capacity: Option<usize>,
+}
+
 /// Events received from [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient).
 ///
 /// These events are delivered via the [`event_rx`](crate::S9AsyncNonBlockingWebSocketClient::event_rx)
@@ -311,18 +553,19 @@ pub trait S9WebSocketClientHandler<C> {
 /// use std::time::Duration;
 ///
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// let options = NonBlockingOptions::new()
-///     .spin_wait_duration(Some(Duration::from_millis(10)))?;
+/// let options = NonBlockingOptions::builder()
+///     .spin_wait_duration(Some(Duration::from_millis(10)))
+///     .build()?;
 ///
 /// let mut client = S9AsyncNonBlockingWebSocketClient::connect("wss://echo.websocket.org", options)?;
-/// let _handle = client.run()?;
+/// client.run()?;
 ///
 /// client.control_tx.send(ControlMessage::SendText("Hello!".to_string()))?;
 ///
 /// loop {
 ///     match client.event_rx.recv() {
-///         Ok(WebSocketEvent::Activated) => {
-///             println!("Client activated");
+///         Ok(WebSocketEvent::Activated(info)) => {
+///             println!("Client activated for {}", info.uri);
 ///         }
 ///         Ok(WebSocketEvent::TextMessage(data)) => {
 ///             println!("Received: {}", String::from_utf8_lossy(&data));
@@ -337,14 +580,26 @@ pub trait S9WebSocketClientHandler<C> {
 ///         Ok(WebSocketEvent::Pong(data)) => {
 ///             println!("Pong: {} bytes", data.len());
 ///         }
-///         Ok(WebSocketEvent::ConnectionClosed(reason)) => {
-///             println!("Closed: {:?}", reason);
+///         Ok(WebSocketEvent::ConnectionClosed(info)) => {
+///             println!("Closed by {:?}: {:?}", info.initiated_by, info.reason);
 ///         }
 ///         Ok(WebSocketEvent::Error(error)) => {
 ///             eprintln!("Error: {}", error);
 ///         }
-///         Ok(WebSocketEvent::Quit) => {
-///             println!("Quitting");
+///         Ok(WebSocketEvent::Heartbeat(at)) => {
+///             println!("Heartbeat at {:?}", at);
+///         }
+///         Ok(WebSocketEvent::ReadsPaused) => {
+///             println!("Reads paused");
+///         }
+///         Ok(WebSocketEvent::ReadsResumed) => {
+///             println!("Reads resumed");
+///         }
+///         Ok(WebSocketEvent::Stats(stats)) => {
+///             println!("Received {} messages", stats.messages_received);
+///         }
+///         Ok(WebSocketEvent::Quit(reason)) => {
+///             println!("Quitting: {:?}", reason);
 ///             break;
 ///         }
 ///         Err(e) => {
@@ -361,7 +616,8 @@ pub enum WebSocketEvent {
     /// Event loop has started and is ready to process messages.
     ///
     /// This is the first event sent after calling [`run()`](crate::S9AsyncNonBlockingWebSocketClient::run).
-    Activated,
+    /// Contains the [`ConnectionInfo`] of the established connection.
+    Activated(ConnectionInfo),
 
     /// A text message was received.
     ///
@@ -387,13 +643,13 @@ pub enum WebSocketEvent {
 
     /// The WebSocket connection was closed.
     ///
-    /// Contains an optional reason string. This event is sent when:
+    /// Contains the [`CloseInfo`] of the closed connection. This event is sent when:
     /// - The server sends a Close frame
     /// - [`ControlMessage::Close`] is sent and acknowledged
     /// - The connection is lost
     ///
     /// A [`Quit`](Self::Quit) event will follow this.
-    ConnectionClosed(Option<String>),
+    ConnectionClosed(CloseInfo),
 
     /// An error occurred during WebSocket operations.
     ///
@@ -406,7 +662,107 @@ pub enum WebSocketEvent {
     /// - [`ConnectionClosed`](Self::ConnectionClosed) (graceful close)
     /// - [`Error`](Self::Error) (error condition)
     /// - [`ControlMessage::ForceQuit`] (immediate shutdown)
-    Quit,
+    ///
+    /// Contains the [`QuitReason`] describing why the event loop terminated.
+    Quit(QuitReason),
+
+    /// Periodic signal that the event loop thread is alive.
+    ///
+    /// Only published if [`NonBlockingOptions::heartbeat_interval`](crate::NonBlockingOptions::heartbeat_interval)
+    /// is configured, regardless of data activity. Contains the time the heartbeat was published.
+    /// Nothing is sent to the server.
+    Heartbeat(Instant),
+
+    /// Socket reads were paused by [`ControlMessage::PauseReads`].
+    ReadsPaused,
+
+    /// Socket reads were resumed by [`ControlMessage::ResumeReads`].
+    ReadsResumed,
+
+    /// Periodic snapshot of the connection statistics.
+    ///
+    /// Optional, only published if [`NonBlockingOptions::stats_interval`](crate::NonBlockingOptions::stats_interval)
+    /// is configured. Consumers who are not interested can skip it, e.g. with
+    /// `if let WebSocketEvent::Stats(_) = event { continue; }`.
+    Stats(ConnectionStats),
+}
+
+impl WebSocketEvent {
+    /// Returns the payload length in bytes for data events, `None` for all other events.
+    ///
+    /// Useful for logging and flow control without copying or destructuring the event. The async client
+    /// records it as `payload_len` field of its trace events when publishing events.
+    #[inline]
+    pub fn payload_len(&self) -> Option<usize> {
+        match self {
+            WebSocketEvent::TextMessage(data)
+            | WebSocketEvent::BinaryMessage(data)
+            | WebSocketEvent::Ping(data)
+            | WebSocketEvent::Pong(data) => Some(data.len()),
+            WebSocketEvent::Activated(_)
+            | WebSocketEvent::ConnectionClosed(_)
+            | WebSocketEvent::Error(_)
+            | WebSocketEvent::Quit(_)
+            | WebSocketEvent::Heartbeat(_)
+            | WebSocketEvent::ReadsPaused
+            | WebSocketEvent::ReadsResumed
+            | WebSocketEvent::Stats(_) => None,
+        }
+    }
+
+    /// Returns `true` for events carrying a message payload
+    /// ([`TextMessage`](Self::TextMessage), [`BinaryMessage`](Self::BinaryMessage), [`Ping`](Self::Ping), [`Pong`](Self::Pong)).
+    #[inline]
+    pub fn is_data_event(&self) -> bool {
+        self.payload_len().is_some()
+    }
+}
+
+/// Maximum number of characters of a text message shown by the [`Display`](fmt::Display) of [`WebSocketEvent`]
+const TEXT_PREVIEW_CHARS: usize = 80;
+
+/// Compact, log friendly representation showing the variant and a payload preview.
+///
+/// Text messages are truncated to 80 characters, binary, ping and pong payloads are shown by length only.
+///
+/// ```
+/// use s9_websocket::WebSocketEvent;
+///
+/// let event = WebSocketEvent::TextMessage(b"hello".to_vec());
+/// assert_eq!(event.to_string(), "TextMessage(5 bytes: \"hello\")");
+///
+/// let event = WebSocketEvent::BinaryMessage(vec![0; 1024]);
+/// assert_eq!(event.to_string(), "BinaryMessage(1024 bytes)");
+/// ```
+impl fmt::Display for WebSocketEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebSocketEvent::Activated(_) => write!(f, "Activated"),
+            WebSocketEvent::TextMessage(data) => {
+                let text = String::from_utf8_lossy(data);
+                let mut chars = text.chars();
+                let preview: String = chars.by_ref().take(TEXT_PREVIEW_CHARS).collect();
+                let ellipsis = if chars.next().is_some() { "..." } else { "" };
+                write!(f, "TextMessage({} bytes: \"{}{}\")", data.len(), preview, ellipsis)
+            },
+            WebSocketEvent::BinaryMessage(data) => write!(f, "BinaryMessage({} bytes)", data.len()),
+            WebSocketEvent::Ping(data) => write!(f, "Ping({} bytes)", data.len()),
+            WebSocketEvent::Pong(data) => write!(f, "Pong({} bytes)", data.len()),
+            WebSocketEvent::ConnectionClosed(info) => {
+                match info.code {
+                    Some(code) => write!(f, "ConnectionClosed(code={}, ", code)?,
+                    None => write!(f, "ConnectionClosed(code=none, ")?,
+                }
+                write!(f, "reason={})", info.reason.as_deref().unwrap_or("none"))
+            },
+            WebSocketEvent::Error(error) => write!(f, "Error({})", error),
+            WebSocketEvent::Quit(_) => write!(f, "Quit"),
+            WebSocketEvent::Heartbeat(_) => write!(f, "Heartbeat"),
+            WebSocketEvent::ReadsPaused => write!(f, "ReadsPaused"),
+            WebSocketEvent::ReadsResumed => write!(f, "ReadsResumed"),
+            WebSocketEvent::Stats(stats) => write!(f, "Stats(received={}, sent={})", stats.messages_received, stats.messages_sent),
+        }
+    }
 }
 
 /// Control messages sent to [`S9AsyncNonBlockingWebSocketClient`](crate::S9AsyncNonBlockingWebSocketClient).
@@ -421,11 +777,12 @@ pub enum WebSocketEvent {
 /// use std::time::Duration;
 ///
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// let options = NonBlockingOptions::new()
-///     .spin_wait_duration(Some(Duration::from_millis(10)))?;
+/// let options = NonBlockingOptions::builder()
+///     .spin_wait_duration(Some(Duration::from_millis(10)))
+///     .build()?;
 ///
 /// let mut client = S9AsyncNonBlockingWebSocketClient::connect("wss://echo.websocket.org", options)?;
-/// let _handle = client.run()?;
+/// client.run()?;
 ///
 /// // Send different types of messages
 /// client.control_tx.send(ControlMessage::SendText("Hello!".to_string()))?;
@@ -446,6 +803,12 @@ pub enum ControlMessage {
     /// The string will be encoded as UTF-8 and sent as a WebSocket text frame.
     SendText(String),
 
+    /// Send a shared text message to the server.
+    ///
+    /// Like [`SendText`](Self::SendText), but carries a reference-counted string. Useful when the
+    /// same text is sent to multiple clients, as cloning the `Arc` avoids copying the string per client.
+    SendTextArc(Arc<str>),
+
     /// Send a binary message to the server.
     ///
     /// The bytes will be sent as a WebSocket binary frame.
@@ -453,18 +816,20 @@ pub enum ControlMessage {
 
     /// Send a Ping frame to the server.
     ///
-    /// The server should respond with a Pong frame. The payload is optional application data.
+    /// The server should respond with a Pong frame. The payload is optional application data
+    /// of at most 125 bytes, larger payloads are rejected with a [`WebSocketEvent::Error`].
     SendPing(Vec<u8>),
 
     /// Send a Pong frame to the server.
     ///
     /// This is typically used to respond to Ping frames, though pong responses are sent
-    /// automatically. The payload is optional application data.
+    /// automatically. The payload is optional application data of at most 125 bytes,
+    /// larger payloads are rejected with a [`WebSocketEvent::Error`].
     SendPong(Vec<u8>),
 
     /// Gracefully close the WebSocket connection.
     ///
-    /// This sends a Close frame to the server and waits for the server's Close frame response.
+    /// This sends a Close frame with code `1000` (normal closure) to the server and waits for the server's Close frame response.
     /// After receiving the response, [`WebSocketEvent::ConnectionClosed`] and
     /// [`WebSocketEvent::Quit`] events will be sent.
     Close(),
@@ -477,4 +842,21 @@ pub enum ControlMessage {
     /// # Note
     /// Prefer [`Close()`](Self::Close) for graceful shutdowns.
     ForceQuit(),
+
+    /// Stop reading from the socket without disconnecting.
+    ///
+    /// Lets a slow consumer drain the event channel before more messages are read. Control messages
+    /// are still processed while paused. A [`WebSocketEvent::ReadsPaused`] event is sent if reads were not
+    /// paused before.
+    ///
+    /// # Note
+    /// The server may disconnect the client if reads stay paused longer than its ping timeout,
+    /// as pong responses are only sent when pings are read. A graceful [`Close()`](Self::Close) only
+    /// completes after reads are resumed, as the server's Close frame must be read.
+    PauseReads(),
+
+    /// Continue reading from the socket after [`PauseReads()`](Self::PauseReads).
+    ///
+    /// A [`WebSocketEvent::ReadsResumed`] event is sent if reads were paused before.
+    ResumeReads(),
 }
\ No newline at end of file
@@ -0,0 +1,314 @@
+//! Integration tests of all client types against a local [`EchoServer`].
+//!
+//! Run with `cargo test --features test-utils`.
+
+use std::io::{ErrorKind, Read, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use s9_websocket::test_utils::{EchoServer, RecordingHandler};
+use s9_websocket::{
+    BlockingOptions, ClosedBy, NonBlockingOptions, PollResult, QuitReason, S9AsyncClientBuilder,
+    S9AsyncNonBlockingWebSocketClient, S9BlockingWebSocketClient, S9NonBlockingWebSocketClient, S9WebSocketError,
+    WebSocketEvent,
+};
+
+const RECV_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Receives events until `Quit`, failing if no event arrives within the timeout
+fn recv_until_quit(client: &S9AsyncNonBlockingWebSocketClient) -> Vec<WebSocketEvent> {
+    let mut events = Vec::new();
+    loop {
+        let event = client.recv_event_timeout(RECV_TIMEOUT).expect("No event received in time");
+        let quit = matches!(event, WebSocketEvent::Quit(_));
+        events.push(event);
+        if quit {
+            return events;
+        }
+    }
+}
+
+#[test]
+fn test_nonblocking_echo() {
+    let server = EchoServer::start().unwrap();
+    let mut client = S9NonBlockingWebSocketClient::connect(server.uri(), NonBlockingOptions::new()).unwrap();
+    let mut handler = RecordingHandler::new().close_after(2);
+
+    client.send_text_message("first").unwrap();
+    client.send_text_message("second").unwrap();
+    let quit_reason = client.run(&mut handler);
+
+    assert!(matches!(quit_reason, QuitReason::GracefulClose { code: Some(1000), .. }));
+    assert!(matches!(handler.events.first(), Some(WebSocketEvent::Activated(info)) if info.status_code == 101));
+    assert_eq!(handler.texts(), vec!["first", "second"]);
+    assert_eq!(handler.close_info().map(|info| info.initiated_by), Some(ClosedBy::Client));
+    assert!(matches!(handler.events.last(), Some(WebSocketEvent::Quit(QuitReason::GracefulClose { .. }))));
+}
+
+#[test]
+fn test_blocking_echo() {
+    let server = EchoServer::start().unwrap();
+    let mut client = S9BlockingWebSocketClient::connect(server.uri(), BlockingOptions::new()).unwrap();
+    let mut handler = RecordingHandler::new().close_after(1);
+
+    client.send_text_message("Hello!").unwrap();
+    let quit_reason = client.run(&mut handler);
+
+    assert!(matches!(quit_reason, QuitReason::GracefulClose { code: Some(1000), .. }));
+    assert_eq!(handler.texts(), vec!["Hello!"]);
+    assert!(matches!(
+        handler.events.as_slice(),
+        [WebSocketEvent::Activated(_), WebSocketEvent::TextMessage(_), WebSocketEvent::ConnectionClosed(_), WebSocketEvent::Quit(_)]
+    ));
+}
+
+#[test]
+fn test_async_echo() {
+    let server = EchoServer::start().unwrap();
+    let mut client = S9AsyncClientBuilder::new().connect(server.uri()).unwrap();
+    client.run().unwrap();
+
+    assert!(matches!(client.recv_event_timeout(RECV_TIMEOUT), Ok(WebSocketEvent::Activated(_))));
+    client.send_text("Hello!").unwrap();
+    assert!(matches!(client.recv_event_timeout(RECV_TIMEOUT), Ok(WebSocketEvent::TextMessage(data)) if data == b"Hello!"));
+
+    client.close().unwrap();
+    let events = recv_until_quit(&client);
+    assert!(matches!(
+        events.as_slice(),
+        [WebSocketEvent::ConnectionClosed(info), WebSocketEvent::Quit(QuitReason::GracefulClose { .. })] if info.initiated_by == ClosedBy::Client
+    ));
+    client.wait().unwrap();
+}
+
+#[test]
+fn test_poll_after_break() {
+    let server = EchoServer::start().unwrap();
+    let mut client = S9NonBlockingWebSocketClient::connect(server.uri(), NonBlockingOptions::new()).unwrap();
+    let mut handler = RecordingHandler::new().close_after(1);
+
+    client.send_text_message("Hello!").unwrap();
+    let quit_reason = loop {
+        if let PollResult::Break(quit_reason) = client.poll_once(&mut handler).unwrap() {
+            break quit_reason;
+        }
+    };
+    let events = handler.events.len();
+
+    assert!(matches!(quit_reason, QuitReason::GracefulClose { .. }));
+    assert!(matches!(client.poll_once(&mut handler), Err(S9WebSocketError::Closed { .. })));
+    // No further on_connection_closed, on_error or on_quit
+    assert_eq!(handler.events.len(), events);
+}
+
+#[test]
+fn test_run_after_quit() {
+    let server = EchoServer::start().unwrap();
+    let mut client = S9NonBlockingWebSocketClient::connect(server.uri(), NonBlockingOptions::new()).unwrap();
+    let mut handler = RecordingHandler::new().close_after(1);
+    client.send_text_message("Hello!").unwrap();
+    let quit_reason = client.run(&mut handler);
+    let events = handler.events.len();
+
+    // No further on_activated, on_error or on_quit
+    assert_eq!(client.run(&mut handler), quit_reason);
+    assert_eq!(handler.events.len(), events);
+
+    let mut client = S9BlockingWebSocketClient::connect(server.uri(), BlockingOptions::new()).unwrap();
+    let mut handler = RecordingHandler::new().close_after(1);
+    client.send_text_message("Hello!").unwrap();
+    let quit_reason = client.run(&mut handler);
+    let events = handler.events.len();
+
+    assert_eq!(client.run(&mut handler), quit_reason);
+    assert_eq!(handler.events.len(), events);
+}
+
+#[test]
+fn test_reconnect() {
+    let server = EchoServer::start().unwrap();
+    let mut client = S9NonBlockingWebSocketClient::connect(server.uri(), NonBlockingOptions::new()).unwrap();
+    client.send_text_message("first connection").unwrap();
+    client.run(&mut RecordingHandler::new().close_after(1));
+
+    // Reconnect to the same target
+    let mut client = S9NonBlockingWebSocketClient::connect_with_headers(
+        client.get_uri(), client.get_headers(), NonBlockingOptions::new()).unwrap();
+    let mut handler = RecordingHandler::new().close_after(1);
+    client.send_text_message("second connection").unwrap();
+    client.run(&mut handler);
+
+    assert_eq!(handler.texts(), vec!["second connection"]);
+}
+
+#[test]
+fn test_redirect() {
+    let server = EchoServer::start().unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let redirect_uri = format!("ws://{}", listener.local_addr().unwrap());
+    let location = server.uri();
+    let redirect = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = [0; 1024];
+        let _ = stream.read(&mut request).unwrap();
+        write!(stream, "HTTP/1.1 302 Found\r\nLocation: {}\r\nContent-Length: 0\r\n\r\n", location).unwrap();
+    });
+
+    let mut client = S9BlockingWebSocketClient::connect(redirect_uri, BlockingOptions::new()).unwrap();
+    let mut handler = RecordingHandler::new().close_after(1);
+    client.send_text_message("redirected").unwrap();
+    client.run(&mut handler);
+
+    assert_eq!(handler.texts(), vec!["redirected"]);
+    redirect.join().unwrap();
+}
+
+#[test]
+fn test_close_with_code() {
+    let server = EchoServer::start().unwrap();
+    let mut client = S9NonBlockingWebSocketClient::connect(server.uri(), NonBlockingOptions::new()).unwrap();
+    let mut handler = RecordingHandler::new();
+
+    client.send_close(4000, "bye").unwrap();
+    let quit_reason = client.run(&mut handler);
+
+    assert_eq!(quit_reason, QuitReason::GracefulClose { code: Some(4000), reason: Some("bye".to_string()) });
+    let close_info = handler.close_info().unwrap();
+    assert_eq!(close_info.code, Some(4000));
+    assert_eq!(close_info.reason.as_deref(), Some("bye"));
+    assert_eq!(close_info.initiated_by, ClosedBy::Client);
+}
+
+#[test]
+fn test_large_binary_message() {
+    let server = EchoServer::start().unwrap();
+    let mut client = S9BlockingWebSocketClient::connect(server.uri(), BlockingOptions::new()).unwrap();
+    let mut handler = RecordingHandler::new().close_after(1);
+    let data: Vec<u8> = (0..4 * 1024 * 1024).map(|i| i as u8).collect();
+
+    client.send_binary_message(data.clone()).unwrap();
+    client.run(&mut handler);
+
+    assert!(matches!(&handler.events[1], WebSocketEvent::BinaryMessage(received) if *received == data));
+}
+
+#[test]
+fn test_ping_pong() {
+    let server = EchoServer::start().unwrap();
+    let mut client = S9NonBlockingWebSocketClient::connect(server.uri(), NonBlockingOptions::new()).unwrap();
+    let mut handler = RecordingHandler::new().close_after(1);
+
+    client.send_ping(b"ping".to_vec()).unwrap();
+    // The echo closes the client after the pong was received
+    client.send_text_message("done").unwrap();
+    client.run(&mut handler);
+
+    assert!(matches!(&handler.events[1], WebSocketEvent::Pong(data) if data == b"ping"));
+    assert_eq!(handler.texts(), vec!["done"]);
+}
+
+#[test]
+fn test_paused_reads_sleep() {
+    let server = EchoServer::start().unwrap();
+    // A heartbeat is published on every iteration, counting the event loop iterations
+    let mut client = S9AsyncClientBuilder::new()
+        .spin_wait_duration(Some(Duration::from_millis(10)))
+        .busy_spin_count(5)
+        .heartbeat_interval(Some(Duration::from_nanos(1)))
+        .connect(server.uri())
+        .unwrap();
+
+    // Paused before the first read, so no read ever counts as idle
+    client.pause_reads().unwrap();
+    client.run().unwrap();
+    thread::sleep(Duration::from_millis(300));
+    client.abort();
+
+    let heartbeats = recv_until_quit(&client).iter()
+        .filter(|event| matches!(event, WebSocketEvent::Heartbeat(_)))
+        .count();
+    // About 5 spinning iterations plus one iteration per 10ms sleep, a busy loop runs orders of magnitude more
+    assert!(heartbeats < 100, "Event loop did not sleep while paused: {} iterations", heartbeats);
+    client.wait().unwrap();
+}
+
+#[test]
+fn test_graceful_shutdown() {
+    let server = EchoServer::start().unwrap();
+    // graceful_shutdown() drains the event channel itself, record the close events in the filter instead
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+    let filter_recorded = Arc::clone(&recorded);
+    let mut client = S9AsyncClientBuilder::new()
+        .event_filter(move |event| {
+            match event {
+                WebSocketEvent::ConnectionClosed(info) => filter_recorded.lock().unwrap().push(WebSocketEvent::ConnectionClosed(info.clone())),
+                WebSocketEvent::Quit(reason) => filter_recorded.lock().unwrap().push(WebSocketEvent::Quit(reason.clone())),
+                _ => {},
+            }
+            true
+        })
+        .connect(server.uri())
+        .unwrap();
+    client.run().unwrap();
+    assert!(matches!(client.recv_event_timeout(RECV_TIMEOUT), Ok(WebSocketEvent::Activated(_))));
+
+    client.graceful_shutdown(RECV_TIMEOUT).unwrap();
+
+    assert!(!client.is_running());
+    // The server echoed the close frame sent by the client
+    assert!(matches!(
+        recorded.lock().unwrap().as_slice(),
+        [WebSocketEvent::ConnectionClosed(info), WebSocketEvent::Quit(QuitReason::GracefulClose { code: Some(1000), .. })]
+            if info.code == Some(1000) && info.initiated_by == ClosedBy::Client
+    ));
+}
+
+#[test]
+fn test_graceful_shutdown_queue_full() {
+    let server = EchoServer::start().unwrap();
+    // Heartbeats block the event loop on the full event channel, so the control channel fills up
+    let mut client = S9AsyncClientBuilder::new()
+        .event_capacity(1)
+        .control_capacity(1)
+        .heartbeat_interval(Some(Duration::from_millis(1)))
+        .connect(server.uri())
+        .unwrap();
+    client.run().unwrap();
+    while !matches!(client.try_send_text("queued"), Err(S9WebSocketError::SendQueueFull)) {
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    let result = client.graceful_shutdown(RECV_TIMEOUT);
+
+    assert!(matches!(result, Err(S9WebSocketError::SendQueueFull)));
+    assert!(!client.is_running());
+}
+
+#[test]
+fn test_graceful_shutdown_stuck_event_loop() {
+    let server = EchoServer::start().unwrap();
+    // The filter blocks the event loop thread until released
+    let released = Arc::new(AtomicBool::new(false));
+    let filter_released = Arc::clone(&released);
+    let mut client = S9AsyncClientBuilder::new()
+        .event_filter(move |_| {
+            while !filter_released.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_millis(10));
+            }
+            true
+        })
+        .connect(server.uri())
+        .unwrap();
+    client.run().unwrap();
+
+    let started_at = Instant::now();
+    let result = client.graceful_shutdown(Duration::from_millis(100));
+
+    assert!(matches!(result, Err(S9WebSocketError::Io(e)) if e.kind() == ErrorKind::TimedOut));
+    assert!(started_at.elapsed() < RECV_TIMEOUT);
+    assert!(client.is_running());
+    released.store(true, Ordering::Relaxed);
+    client.wait().unwrap();
+}